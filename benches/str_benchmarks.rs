@@ -40,33 +40,60 @@ fn benchmark_nstr(c: &mut Criterion) {
     // group.sampling_mode(criterion::SamplingMode::Flat);
 
     let algs: Vec<(&str, Box<AlgFn>)> = vec![
+        ("arith_ncd", Box::new(nstr::arith_ncd)),
         ("bag", Box::new(nstr::bag)),
+        ("bhattacharyya", Box::new(nstr::bhattacharyya)),
+        ("bm25", Box::new(nstr::bm25)),
+        ("braun_blanquet", Box::new(nstr::braun_blanquet)),
+        ("bray_curtis", Box::new(nstr::bray_curtis)),
+        ("bwtrle_ncd", Box::new(nstr::bwtrle_ncd)),
+        ("canberra", Box::new(nstr::canberra)),
+        ("chebyshev", Box::new(nstr::chebyshev)),
         ("cosine", Box::new(nstr::cosine)),
         ("damerau_levenshtein", Box::new(nstr::damerau_levenshtein)),
         (
             "damerau_levenshtein_restricted",
             Box::new(nstr::damerau_levenshtein_restricted),
         ),
+        ("editex", Box::new(nstr::editex)),
         ("entropy_ncd", Box::new(nstr::entropy_ncd)),
+        ("euclidean", Box::new(nstr::euclidean)),
+        ("eudex", Box::new(nstr::eudex)),
         ("hamming", Box::new(nstr::hamming)),
+        ("hellinger", Box::new(nstr::hellinger)),
         ("jaccard", Box::new(nstr::jaccard)),
         ("jaro_winkler", Box::new(nstr::jaro_winkler)),
         ("jaro", Box::new(nstr::jaro)),
+        ("jensen_shannon", Box::new(nstr::jensen_shannon)),
+        ("kulczynski", Box::new(nstr::kulczynski)),
+        ("kullback_leibler", Box::new(nstr::kullback_leibler)),
         ("lcsseq", Box::new(nstr::lcsseq)),
         ("lcsstr", Box::new(nstr::lcsstr)),
         ("length", Box::new(nstr::length)),
         ("levenshtein", Box::new(nstr::levenshtein)),
         ("lig3", Box::new(nstr::lig3)),
+        ("lz77_ncd", Box::new(nstr::lz77_ncd)),
+        ("manhattan", Box::new(nstr::manhattan)),
+        ("minkowski", Box::new(nstr::minkowski)),
         ("mlipns", Box::new(nstr::mlipns)),
+        ("mra", Box::new(nstr::mra)),
+        ("ochiai", Box::new(nstr::ochiai)),
         ("overlap", Box::new(nstr::overlap)),
         ("prefix", Box::new(nstr::prefix)),
+        ("qgram", Box::new(nstr::qgram)),
         ("ratcliff_obershelp", Box::new(nstr::ratcliff_obershelp)),
         ("roberts", Box::new(nstr::roberts)),
+        ("rogers_tanimoto", Box::new(nstr::rogers_tanimoto)),
         ("sift4_common", Box::new(nstr::sift4_common)),
         ("sift4_simple", Box::new(nstr::sift4_simple)),
+        ("simpson", Box::new(nstr::simpson)),
         ("smith_waterman", Box::new(nstr::smith_waterman)),
+        ("soft_tfidf", Box::new(nstr::soft_tfidf)),
+        ("sokal_michener", Box::new(nstr::sokal_michener)),
         ("sorensen_dice", Box::new(nstr::sorensen_dice)),
+        ("strcmp95", Box::new(nstr::strcmp95)),
         ("suffix", Box::new(nstr::suffix)),
+        ("tfidf", Box::new(nstr::tfidf)),
         ("tversky", Box::new(nstr::tversky)),
         ("yujian_bo", Box::new(nstr::yujian_bo)),
     ];
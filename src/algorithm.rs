@@ -75,7 +75,7 @@ pub trait Algorithm<R> {
     }
 }
 
-fn bigrams(s: &str) -> impl Iterator<Item = (char, char)> + '_ {
+pub(crate) fn bigrams(s: &str) -> impl Iterator<Item = (char, char)> + '_ {
     s.chars().zip(s.chars().skip(1))
 }
 
@@ -0,0 +1,147 @@
+//! Arithmetic coding Normalized Compression Distance
+#![cfg(feature = "std")]
+use crate::compression::{Compressor, NCD};
+use std::collections::HashMap;
+
+/// [Normalized Compression Distance] based on adaptive [arithmetic coding].
+///
+/// Unlike [`EntropyNCD`](crate::EntropyNCD), the model learns probabilities of bytes
+/// as it goes and takes into account the preceding bytes (the context),
+/// so the order of elements matters. See [`ArithCoder`] for details.
+///
+///     use textdistance::{Algorithm, ArithNCD};
+///     let a = ArithNCD::default();
+///     assert!(a.for_str("abc", "cba").nval() > a.for_str("abc", "abc").nval());
+///
+/// [Normalized Compression Distance]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [arithmetic coding]: https://en.wikipedia.org/wiki/Arithmetic_coding
+pub type ArithNCD = NCD<ArithCoder>;
+
+/// Adaptive [arithmetic coding] compressor with [PPM] context modeling.
+///
+/// Each byte is predicted from the counts of bytes seen so far after the same
+/// preceding `order` bytes (the context). If the byte wasn't seen in the context yet,
+/// the coder encodes an escape symbol and tries a shorter context, down to the uniform
+/// distribution over all 256 bytes.
+///
+/// The compressed size is the ideal code length the coder would produce,
+/// without the few bits needed to flush the coder state.
+///
+/// [arithmetic coding]: https://en.wikipedia.org/wiki/Arithmetic_coding
+/// [PPM]: https://en.wikipedia.org/wiki/Prediction_by_partial_matching
+pub struct ArithCoder {
+    /// The longest context (in bytes) to use for predictions. Default: 2.
+    pub order: usize,
+}
+
+impl Default for ArithCoder {
+    fn default() -> Self {
+        Self { order: 2 }
+    }
+}
+
+/// Byte counts seen in a context.
+#[derive(Default)]
+struct Context {
+    counts: HashMap<u8, usize>,
+    total: usize,
+}
+
+impl Compressor for ArithCoder {
+    fn compressed_size(&self, data: &[u8]) -> f64 {
+        let mut contexts: HashMap<&[u8], Context> = HashMap::new();
+        let mut size = 0.;
+        for (i, b) in data.iter().enumerate() {
+            let mut found = false;
+            for order in (0..=self.order.min(i)).rev() {
+                let ctx = contexts.entry(&data[i - order..i]).or_default();
+                if !found {
+                    // PPM method C: the escape has the weight of the number of distinct bytes
+                    let weight = (ctx.total + ctx.counts.len()) as f64;
+                    if let Some(count) = ctx.counts.get(b) {
+                        size += (weight / *count as f64).log2();
+                        found = true;
+                    } else if ctx.total != 0 {
+                        size += (weight / ctx.counts.len() as f64).log2();
+                    }
+                }
+                *ctx.counts.entry(*b).or_insert(0) += 1;
+                ctx.total += 1;
+            }
+            if !found {
+                size += 8.;
+            }
+        }
+        size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::str::arith_ncd;
+    use crate::Algorithm;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("a", "", 1.)]
+    #[case("test", "test", 0.238_144)]
+    #[case("test", "tset", 0.479_771)]
+    #[case("test", "nani", 1.)]
+    #[case("abcdef", "abcdef", 0.161_980)]
+    #[case("abcdef", "fedcba", 0.529_945)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = arith_ncd(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "arith_ncd({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[test]
+    fn compressed_size() {
+        let c = ArithCoder::default();
+        assert!(c.compressed_size(b"") == 0.);
+        assert!(c.compressed_size(b"a") == 8.);
+        // the second "a" is predicted with probability 1/2
+        assert!(c.compressed_size(b"aa") == 9.);
+        assert!(c.compressed_size(b"aaa") == 10.);
+        // "b" is encoded after an escape
+        assert!(c.compressed_size(b"ab") == 17.);
+    }
+
+    #[test]
+    fn order() {
+        let s = b"abcabcabcabcabcabc";
+        let c0 = ArithCoder { order: 0 }.compressed_size(s);
+        let c1 = ArithCoder { order: 1 }.compressed_size(s);
+        let c2 = ArithCoder { order: 2 }.compressed_size(s);
+        assert!(c1 < c0);
+        assert!(c2 < c0);
+        assert!(is_close(c1, 36.076_816));
+    }
+
+    #[test]
+    fn order_sensitive() {
+        let a = ArithNCD::default();
+        let same = a.for_str("abcdef", "abcdef").nval();
+        let reversed = a.for_str("abcdef", "fedcba").nval();
+        assert!(reversed > same);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = arith_ncd(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, arith_ncd(&s2, &s1)));
+        }
+    }
+}
@@ -7,7 +7,11 @@ use crate::{Algorithm, Result};
 ///
 /// [Bag distance]: http://www-db.disi.unibo.it/research/papers/SPIRE02.pdf
 #[derive(Default)]
-pub struct Bag {}
+pub struct Bag {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
 
 impl Algorithm<usize> for Bag {
     fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<usize>
@@ -15,8 +19,8 @@ impl Algorithm<usize> for Bag {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let d1 = c1.diff_count(&c2);
         let d2 = c2.diff_count(&c1);
         let l1 = c1.count();
@@ -35,6 +39,7 @@ impl Algorithm<usize> for Bag {
 #[cfg(test)]
 mod tests {
     use crate::str::bag;
+    use crate::{Algorithm, Bag};
     use assert2::assert;
     use rstest::rstest;
 
@@ -54,4 +59,11 @@ mod tests {
         let act = bag(s1, s2);
         assert!(act == exp);
     }
+
+    #[test]
+    fn as_set() {
+        let a = Bag { as_set: true };
+        assert!(a.for_str("aaab", "ab").val() == 0);
+        assert!(a.for_str("aaab", "abcc").val() == 1);
+    }
 }
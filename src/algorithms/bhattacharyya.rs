@@ -0,0 +1,100 @@
+//! Bhattacharyya coefficient
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Bhattacharyya coefficient] is the amount of overlap between distributions
+/// of elements in two sequences.
+///
+/// The coefficient is a similarity on the interval from 0.0 to 1.0.
+/// Bhattacharyya distance is `-ln(BC)` and it's unbounded. If you need a distance,
+/// use [`Hellinger`] instead.
+///
+///     use textdistance::{Algorithm, Bhattacharyya};
+///     let a = Bhattacharyya::default();
+///     assert!(a.for_words("the cat sat", "the cat sat").nval() == 1.);
+///     assert!(a.for_words("the cat sat", "a dog ran").nval() == 0.);
+///
+/// [Bhattacharyya coefficient]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+/// [`Hellinger`]: crate::Hellinger
+#[derive(Default)]
+pub struct Bhattacharyya {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Bhattacharyya {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let ic: f64 = c1
+                    .pairs(&c2)
+                    .map(|(e1, e2)| ((e1 * e2) as f64).sqrt())
+                    .sum();
+                (ic / ((n1 * n2) as f64).sqrt()).min(1.)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::bhattacharyya;
+    use crate::{Algorithm, Bhattacharyya};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "abc", 0.)]
+    #[case("test", "test", 1.)]
+    #[case("abc", "cba", 1.)]
+    #[case("aaa", "bbb", 0.)]
+    #[case("test", "text", 0.75)]
+    #[case("aab", "abb", 0.942_809)]
+    #[case("nelson", "neilsen", 0.835_431)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = bhattacharyya(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "bhattacharyya({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[test]
+    fn as_set() {
+        let a = Bhattacharyya { as_set: true };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 1.));
+        assert!(Bhattacharyya::default().for_str("aaab", "ab").nval() < 1.);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = bhattacharyya(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, bhattacharyya(&s2, &s1)));
+        }
+    }
+}
@@ -0,0 +1,334 @@
+//! Okapi BM25 ranking function
+#![cfg(feature = "std")]
+use crate::algorithm::bigrams;
+use crate::corpus::{stable_hash, std_hash, Corpus};
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// [Okapi BM25] ranking function and its BM25+ variant by Lv and Zhai.
+///
+/// It scores how relevant the second sequence (document) is to the first one (query).
+/// Each element of the query that occurs in the document adds its
+/// [inverse document frequency] in the [`Corpus`] multiplied by a saturating function
+/// of how many times it occurs in the document. Documents longer than the average
+/// document in the corpus get lower scores.
+///
+/// The score is normalized by the highest score the query could get, so the result
+/// is a similarity from 0.0 to 1.0. Only a document containing each query element
+/// infinitely many times can reach 1.0 (or any document if `k1` is zero),
+/// so the query itself usually scores lower. The metric isn't symmetric.
+///
+/// Use [`BM25::search`] to find the most relevant documents in a collection.
+///
+///     use textdistance::{Algorithm, BM25};
+///     let docs = ["the quick brown fox", "the lazy dog", "the quick dog jumps"];
+///     let a = BM25 {
+///         corpus: docs.into_iter().collect(),
+///         ..Default::default()
+///     };
+///     let r1 = a.for_words("quick dog", "the quick dog jumps").nval();
+///     let r2 = a.for_words("quick dog", "the lazy dog").nval();
+///     assert!(r1 > r2);
+///     assert!(a.for_words("quick dog", "hello world").nval() == 0.);
+///
+/// [Okapi BM25]: https://en.wikipedia.org/wiki/Okapi_BM25
+/// [inverse document frequency]: https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+pub struct BM25 {
+    /// Document frequencies of elements and the average document length. Default: empty.
+    ///
+    /// With an empty corpus, all elements have the same weight and the document length
+    /// doesn't affect the score.
+    pub corpus: Corpus,
+
+    /// How fast the score saturates with the number of occurrences of an element.
+    /// Zero means that only the presence of the element matters. Default: 1.2.
+    pub k1: f64,
+
+    /// How much the document length affects the score, from 0.0 (not at all)
+    /// to 1.0 (the score is inversely proportional to the length). Default: 0.75.
+    pub b: f64,
+
+    /// The bonus for each query element that occurs in the document,
+    /// so that long documents aren't penalized too much.
+    /// Zero is the classic BM25, a positive value (usually 1.0) is BM25+. Default: 0.
+    pub delta: f64,
+}
+
+impl Default for BM25 {
+    fn default() -> Self {
+        Self {
+            corpus: Corpus::default(),
+            k1: 1.2,
+            b: 0.75,
+            delta: 0.,
+        }
+    }
+}
+
+impl BM25 {
+    /// Inverse document frequency of the element, as in Lucene.
+    /// Unlike the original one, it is always positive.
+    fn idf(&self, hash: u64) -> f64 {
+        let n = self.corpus.docs() as f64;
+        let df = self.corpus.df_of(hash) as f64;
+        (1. + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    /// The score of the document for the query and the highest score the query can get.
+    /// The elements are looked up in the corpus by the given hash function.
+    fn score<E, H>(&self, query: &Counter<E>, doc: &Counter<E>, hash: H) -> (f64, f64)
+    where
+        E: Eq + Hash,
+        H: Fn(&E) -> u64,
+    {
+        let avg_len = self.corpus.avg_len();
+        let len_norm = if avg_len == 0. {
+            1.
+        } else {
+            1. - self.b + self.b * doc.count() as f64 / avg_len
+        };
+        let mut score = 0.;
+        let mut max = 0.;
+        for e in query.keys() {
+            let weight = query.get(e).map_or(0., |n| *n as f64) * self.idf(hash(e));
+            max += weight * (self.k1 + 1. + self.delta);
+            if let Some(tf) = doc.get(e) {
+                let tf = *tf as f64;
+                score += weight * (tf * (self.k1 + 1.) / (tf + self.k1 * len_norm) + self.delta);
+            }
+        }
+        (score, max)
+    }
+
+    /// Find the `k` documents most relevant to the query.
+    ///
+    /// The query and the documents are split into words. The result is a list of
+    /// indices of the documents and their normalized scores, the most relevant first.
+    /// Documents that have no words in common with the query are skipped.
+    ///
+    ///     use textdistance::BM25;
+    ///     let docs = ["the quick brown fox", "the lazy dog", "the quick dog jumps"];
+    ///     let a = BM25 {
+    ///         corpus: docs.into_iter().collect(),
+    ///         ..Default::default()
+    ///     };
+    ///     let found = a.search("quick dog", docs, 2);
+    ///     assert!(found[0].0 == 2);
+    ///     assert!(found.len() == 2);
+    ///
+    pub fn search<'a, I>(&self, query: &str, docs: I, k: usize) -> Vec<(usize, f64)>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let query = Counter::from_iter(query.split_whitespace());
+        let mut found: Vec<(usize, f64)> = docs
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, doc)| {
+                let doc = Counter::from_iter(doc.split_whitespace());
+                let (score, max) = self.score(&query, &doc, stable_hash);
+                (score > 0.).then_some((i, (score / max).min(1.)))
+            })
+            .collect();
+        // the best first, and the earlier document if scores are equal
+        let cmp = |a: &(usize, f64), b: &(usize, f64)| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0));
+        if found.len() > k {
+            found.select_nth_unstable_by(k, cmp);
+            found.truncate(k);
+        }
+        found.sort_unstable_by(cmp);
+        found
+    }
+
+    fn bm25<C, E, H>(&self, s1: C, s2: C, hash: H) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+        H: Fn(&E) -> u64,
+    {
+        let query = Counter::from_iter(s1);
+        let doc = Counter::from_iter(s2);
+        let len1 = query.count();
+        let len2 = doc.count();
+        let res = match (len1, len2) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let (score, max) = self.score(&query, &doc, hash);
+                (score / max).min(1.)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1,
+            len2,
+        }
+    }
+}
+
+impl Algorithm<f64> for BM25 {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+    {
+        self.bm25(s1, s2, std_hash)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.bm25(s1.chars(), s2.chars(), stable_hash)
+    }
+
+    fn for_words(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.bm25(s1.split_whitespace(), s2.split_whitespace(), stable_hash)
+    }
+
+    fn for_bigrams(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.bm25(bigrams(s1), bigrams(s2), stable_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::str::bm25;
+    use crate::{Algorithm, BM25};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    const DOCS: [&str; 3] = ["the quick brown fox", "the lazy dog", "the quick dog jumps"];
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    fn bm25_docs() -> BM25 {
+        BM25 {
+            corpus: DOCS.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("", "neilsen", 0.)]
+    #[case("abc", "def", 0.)]
+    #[case("abc", "cba", 0.454_545)]
+    #[case("test", "text", 0.426_136)]
+    #[case("aab", "abb", 0.511_364)]
+    #[case("ab", "abab", 0.625)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = bm25(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "bm25({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("quick dog", "the quick brown fox", 0.219_124)]
+    #[case("quick dog", "the lazy dog", 0.245_536)]
+    #[case("quick dog", "the quick dog jumps", 0.438_247)]
+    #[case("quick dog", "hello world", 0.)]
+    #[case("fox", "fox", 0.647_059)]
+    fn for_words(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = bm25_docs().for_words(s1, s2).nval();
+        let ok = is_close(act, exp);
+        assert!(ok, "for_words({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("quick dog", "the quick brown fox", 0.306_897)]
+    #[case("quick dog", "the quick dog jumps", 0.613_795)]
+    #[case("fox", "fox", 0.757_353)]
+    fn bm25_plus(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = BM25 {
+            delta: 1.,
+            ..bm25_docs()
+        };
+        let act = a.for_words(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "for_words({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+
+    #[test]
+    fn k1() {
+        let a = BM25 {
+            k1: 0.,
+            ..bm25_docs()
+        };
+        assert!(a.for_words("quick dog", "the quick dog jumps").nval() == 1.);
+        assert!(a.for_words("dog", "dog dog dog").nval() == 1.);
+    }
+
+    #[test]
+    fn b() {
+        let a = BM25 {
+            b: 0.,
+            ..bm25_docs()
+        };
+        assert!(is_close(
+            a.for_words("quick dog", "the lazy dog").nval(),
+            0.227_273
+        ));
+        let r1 = a.for_words("fox", "fox").nval();
+        let r2 = a.for_words("fox", "the quick brown fox").nval();
+        assert!(r1 == r2);
+    }
+
+    #[rstest]
+    #[case("quick dog", 3, vec![2, 1, 0])]
+    #[case("quick dog", 2, vec![2, 1])]
+    #[case("quick dog", 0, vec![])]
+    #[case("fox", 3, vec![0])]
+    #[case("the", 3, vec![1, 0, 2])]
+    #[case("hello", 3, vec![])]
+    #[case("", 3, vec![])]
+    fn search(#[case] query: &str, #[case] k: usize, #[case] exp: Vec<usize>) {
+        let found = bm25_docs().search(query, DOCS, k);
+        let act: Vec<usize> = found.iter().map(|(i, _)| *i).collect();
+        assert!(act == exp);
+    }
+
+    #[test]
+    fn search_scores() {
+        let a = bm25_docs();
+        for (i, score) in a.search("quick dog", DOCS, 3) {
+            assert!(score == a.for_words("quick dog", DOCS[i]).nval());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = bm25(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+        }
+
+        #[test]
+        fn prop_search(query in "[a-c ]*", docs: Vec<String>, k in 0..5usize) {
+            let docs: Vec<&str> = docs.iter().map(String::as_str).collect();
+            let a = BM25 {
+                corpus: docs.iter().copied().collect(),
+                delta: 1.,
+                ..Default::default()
+            };
+            let found = a.search(&query, docs.iter().copied(), k);
+            prop_assert!(found.len() <= k);
+            for w in found.windows(2) {
+                prop_assert!(w[0].1 >= w[1].1);
+            }
+        }
+    }
+}
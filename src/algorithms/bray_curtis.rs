@@ -0,0 +1,88 @@
+//! Bray-Curtis dissimilarity
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Bray-Curtis dissimilarity] is the number of elements that are not shared by the sequences
+/// divided by the total number of elements in both sequences.
+///
+/// For multisets (the default mode), it is the same as 1 - [`SorensenDice`] similarity.
+///
+/// [Bray-Curtis dissimilarity]: https://en.wikipedia.org/wiki/Bray%E2%80%93Curtis_dissimilarity
+/// [`SorensenDice`]: crate::SorensenDice
+#[derive(Default)]
+pub struct BrayCurtis {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for BrayCurtis {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 0.,
+            (_, 0) | (0, _) => 1.,
+            (_, _) => {
+                let diff = c1.diff_count(&c2) + c2.diff_count(&c1);
+                diff as f64 / (n1 + n2) as f64
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: true,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::bray_curtis;
+    use crate::{Algorithm, BrayCurtis};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("", "abc", 1.)]
+    #[case("abc", "abc", 0.)]
+    #[case("test", "text", 0.25)]
+    #[case("nelson", "neilsen", 3. / 13.)]
+    #[case("aaab", "ab", 1. / 3.)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = bray_curtis(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "bray_curtis({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("test", "text", 1. / 3.)]
+    #[case("nelson", "neilsen", 0.2)]
+    #[case("aaab", "ab", 0.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = BrayCurtis { as_set: true };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "bray_curtis({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+}
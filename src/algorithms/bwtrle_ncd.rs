@@ -0,0 +1,174 @@
+//! BWT+RLE Normalized Compression Distance
+#![cfg(feature = "std")]
+use crate::compression::{Compressor, NCD};
+use alloc::vec::Vec;
+
+/// [Normalized Compression Distance] based on [Burrows–Wheeler transform]
+/// followed by [run-length encoding].
+///
+/// BWT groups together bytes that appear in the same context, and RLE
+/// then compresses the resulting runs of the same byte.
+///
+///     use textdistance::{Algorithm, BWTRLENCD};
+///     let a = BWTRLENCD::default();
+///     assert!(a.for_str("banana", "banana").nval() < a.for_str("banana", "ananab").nval());
+///
+/// [Normalized Compression Distance]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [Burrows–Wheeler transform]: https://en.wikipedia.org/wiki/Burrows%E2%80%93Wheeler_transform
+/// [run-length encoding]: https://en.wikipedia.org/wiki/Run-length_encoding
+pub type BWTRLENCD = NCD<BWTRLE>;
+
+/// [Burrows–Wheeler transform] + [run-length encoding] compressor.
+///
+/// Each run of the same byte is stored as the byte followed by the run length
+/// in [Elias gamma coding]. The BWT primary index is not included in the size.
+///
+/// [Burrows–Wheeler transform]: https://en.wikipedia.org/wiki/Burrows%E2%80%93Wheeler_transform
+/// [run-length encoding]: https://en.wikipedia.org/wiki/Run-length_encoding
+/// [Elias gamma coding]: https://en.wikipedia.org/wiki/Elias_gamma_coding
+#[derive(Default)]
+pub struct BWTRLE {}
+
+impl BWTRLE {
+    /// The Burrows–Wheeler transform of the data.
+    ///
+    /// The data is considered to be terminated by a unique byte smaller than all others.
+    /// The terminator itself is not included in the output.
+    ///
+    /// The rotations are sorted by prefix doubling in O(n log² n) time,
+    /// so long repetitive inputs are fine.
+    ///
+    ///     use textdistance::compression::BWTRLE;
+    ///     assert_eq!(BWTRLE::transform(b"banana"), b"annbaa");
+    ///
+    pub fn transform(data: &[u8]) -> Vec<u8> {
+        rotations(data)
+            .into_iter()
+            .filter(|i| *i != 0)
+            .map(|i| data[i - 1])
+            .collect()
+    }
+}
+
+/// Start positions of rotations of the data with the terminator, in sorted order.
+///
+/// Each round sorts rotations by their first `2k` bytes using the ranks
+/// of the first `k` bytes from the previous round. Since the terminator is unique,
+/// all ranks become distinct after at most log n rounds.
+fn rotations(data: &[u8]) -> Vec<usize> {
+    let n = data.len() + 1;
+    let mut rank: Vec<usize> = data.iter().map(|b| usize::from(*b) + 1).collect();
+    rank.push(0);
+    let mut order: Vec<usize> = (0..n).collect();
+    let mut next = vec![0; n];
+    let mut k = 1;
+    loop {
+        let key = |i: usize| (rank[i], rank[(i + k) % n]);
+        order.sort_unstable_by_key(|i| key(*i));
+        next[order[0]] = 0;
+        for w in order.windows(2) {
+            next[w[1]] = next[w[0]] + usize::from(key(w[0]) != key(w[1]));
+        }
+        core::mem::swap(&mut rank, &mut next);
+        if rank[order[n - 1]] == n - 1 {
+            return order;
+        }
+        k *= 2;
+    }
+}
+
+/// How many bits it takes to store the positive number in Elias gamma coding.
+fn gamma_len(n: usize) -> usize {
+    debug_assert!(n > 0);
+    (2 * n.ilog2() + 1) as usize
+}
+
+impl Compressor for BWTRLE {
+    fn compressed_size(&self, data: &[u8]) -> f64 {
+        let transformed = Self::transform(data);
+        let size: usize = transformed
+            .chunk_by(|a, b| a == b)
+            .map(|run| 8 + gamma_len(run.len()))
+            .sum();
+        size as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::str::bwtrle_ncd;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("a", "", 1.)]
+    #[case("test", "test", 0.689_655)]
+    #[case("test", "nani", 1.)]
+    #[case("aaaa", "aaaa", 0.153_846)]
+    #[case("banana", "banana", 0.15)]
+    #[case("banana", "ananab", 0.325)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = bwtrle_ncd(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "bwtrle_ncd({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case(b"", b"")]
+    #[case(b"a", b"a")]
+    #[case(b"banana", b"annbaa")]
+    #[case(b"abracadabra", b"ardrcaaaabb")]
+    fn transform(#[case] s: &[u8], #[case] exp: &[u8]) {
+        assert!(BWTRLE::transform(s) == exp);
+    }
+
+    #[test]
+    fn transform_long_run() {
+        let s = vec![b'a'; 100_000];
+        assert!(BWTRLE::transform(&s) == s);
+        let mut s = b"ab".repeat(50_000);
+        s.push(b'c');
+        let t = BWTRLE::transform(&s);
+        assert!(t.len() == s.len());
+        assert!(t[0] == b'c');
+        assert!(t[1..50_000].iter().all(|b| *b == b'b'));
+    }
+
+    #[rstest]
+    #[case(b"", 0)]
+    #[case(b"a", 8 + 1)]
+    #[case(b"ab", 2 * (8 + 1))]
+    #[case(b"aa", 8 + 3)]
+    #[case(b"aaa", 8 + 3)]
+    #[case(b"banana", 40)]
+    #[case(&[b'a'; 130], 8 + 15)]
+    fn compressed_size(#[case] s: &[u8], #[case] exp: usize) {
+        assert!(BWTRLE::default().compressed_size(s) == exp as f64);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = bwtrle_ncd(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, bwtrle_ncd(&s2, &s1)));
+        }
+
+        #[test]
+        fn prop_transform(s in proptest::collection::vec(0..4u8, 0..50)) {
+            let mut suffixes: Vec<usize> = (0..=s.len()).collect();
+            suffixes.sort_unstable_by_key(|i| &s[*i..]);
+            let exp: Vec<u8> = suffixes.into_iter().filter(|i| *i != 0).map(|i| s[i - 1]).collect();
+            prop_assert!(BWTRLE::transform(&s) == exp);
+        }
+    }
+}
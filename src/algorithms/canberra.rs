@@ -0,0 +1,86 @@
+//! Canberra distance
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Canberra distance] is a weighted version of [`Manhattan`] distance
+/// where the difference in counts of each element is divided by the sum of the counts.
+///
+/// The distance is normalized by the number of unique elements in both sequences.
+/// For sets, it is the same as 1 - [`Jaccard`] similarity.
+///
+/// [Canberra distance]: https://en.wikipedia.org/wiki/Canberra_distance
+/// [`Manhattan`]: crate::Manhattan
+/// [`Jaccard`]: crate::Jaccard
+#[derive(Default)]
+pub struct Canberra {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Canberra {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let mut dist = 0.;
+        let mut size: usize = 0;
+        for (n1, n2) in c1.pairs(&c2) {
+            dist += n1.abs_diff(n2) as f64 / (n1 + n2) as f64;
+            size += 1;
+        }
+        Result {
+            abs: if size == 0 { 0. } else { dist / size as f64 },
+            is_distance: true,
+            max: 1.,
+            len1: c1.count(),
+            len2: c2.count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::canberra;
+    use crate::{Algorithm, Canberra};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("", "abc", 1.)]
+    #[case("abc", "abc", 0.)]
+    #[case("test", "text", 0.5)]
+    #[case("nelson", "neilsen", 0.388_889)]
+    #[case("aaab", "ab", 0.25)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = canberra(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "canberra({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("test", "text", 0.5)]
+    #[case("nelson", "neilsen", 1. / 3.)]
+    #[case("aaab", "ab", 0.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = Canberra { as_set: true };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "canberra({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Chebyshev distance
+#![cfg(feature = "std")]
+use crate::{Algorithm, Minkowski, Result};
+
+/// [Chebyshev distance] (aka L-infinity distance) is the biggest absolute difference of element counts.
+///
+/// It is [`Minkowski`] distance with p=infinity,
+/// see its documentation for how the distance is normalized.
+///
+/// [Chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
+#[derive(Default)]
+pub struct Chebyshev {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Chebyshev {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let a = Minkowski {
+            p: f64::INFINITY,
+            as_set: self.as_set,
+        };
+        a.for_iter(s1, s2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::chebyshev;
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("nelson", "", 1.)]
+    #[case("abc", "cba", 0.)]
+    #[case("abc", "def", 1.)]
+    #[case("test", "text", 0.5)]
+    #[case("aab", "abb", 0.5)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = chebyshev(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "chebyshev({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+}
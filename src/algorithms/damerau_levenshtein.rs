@@ -1,10 +1,8 @@
 //! Damerau-Levenshtein distance
 #![cfg(feature = "std")]
-use crate::{Algorithm, Result};
-use alloc::vec;
-use alloc::vec::Vec;
+use crate::scratch::{element_ids, reset, two_rows, with_chars};
+use crate::{Algorithm, Result, Scratch, WithScratch};
 use core::hash::Hash;
-use std::collections::HashMap;
 
 /// [Damerau-Levenshtein distance] is an edit distance between two sequences.
 ///
@@ -15,6 +13,13 @@ use std::collections::HashMap;
 /// substitutions of a single character, or transposition of two adjacent characters)
 /// required to change one text into the other.
 ///
+/// The restricted variant (optimal string alignment) keeps only three rows
+/// of the matrix in memory. The unrestricted one also keeps a row for each distinct
+/// element that occurs in both sequences, so it takes O(k * l2) memory,
+/// where k is the number of such elements and l2 is the length of the second sequence.
+///
+/// With [`WithScratch`], neither `for_str_with` nor `for_vec_with` allocates after warm-up.
+///
 /// [Damerau-Levenshtein distance]: https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance
 pub struct DamerauLevenshtein {
     /// If false (default), allow adjacent transpositions.
@@ -46,37 +51,68 @@ impl Default for DamerauLevenshtein {
 }
 
 impl DamerauLevenshtein {
-    fn get_unrestricted<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+    /// Lowrance-Wagner algorithm.
+    ///
+    /// The transposition cost depends on the row of the last occurrence of each element,
+    /// so besides the previous and the current rows, a copy of the row is kept
+    /// for the last occurrence of each element of `s1` that also occurs in `s2`.
+    /// Rows for other elements are never read and so never stored.
+    /// So, the memory is O(k * l2) for k distinct elements occurring in both sequences.
+    ///
+    /// The elements are replaced by ids from 0 to `n_ids`, so that all the state
+    /// is stored in plain vectors of the scratch.
+    fn get_unrestricted(
+        &self,
+        scratch: &mut Scratch,
+        s1: &[usize],
+        s2: &[usize],
+        n_ids: usize,
+    ) -> Result<usize> {
         let l1 = s1.len();
         let l2 = s2.len();
         let max_dist = l2 + l1;
+        let width = l2 + 2;
 
-        let mut mat: Vec<Vec<usize>> = vec![vec![0; l2 + 2]; l1 + 2];
-        mat[0][0] = max_dist;
-        for i in 0..=l1 {
-            mat[i + 1][0] = max_dist;
-            mat[i + 1][1] = i;
+        let Scratch {
+            rows: buf,
+            flags1: in_s2,
+            last_rows,
+            saved_rows: rows,
+            ..
+        } = scratch;
+        reset(buf, width * 2, 0);
+        buf[0] = max_dist;
+        for (i2, cell) in buf[1..width].iter_mut().enumerate() {
+            *cell = i2;
         }
-        for i in 0..=l2 {
-            mat[0][i + 1] = max_dist;
-            mat[1][i + 1] = i;
+        reset(in_s2, n_ids, false);
+        for &c2 in s2 {
+            in_s2[c2] = true;
         }
+        // the element -> (its last position in s1, its row slot in `rows`)
+        reset(last_rows, n_ids, None);
+        rows.clear();
 
-        let mut char_map: HashMap<&E, usize> = HashMap::new();
-        for (i1, c1) in s1.iter().enumerate() {
+        for (i1, &c1) in s1.iter().enumerate() {
+            let (prev, row) = two_rows(buf, width, i1);
             let mut db = 0;
             let i1 = i1 + 1;
+            row[0] = max_dist;
+            row[1] = i1;
 
-            for (i2, c2) in s2.iter().enumerate() {
+            for (i2, &c2) in s2.iter().enumerate() {
                 let i2 = i2 + 1;
-                let last = *char_map.get(&c2).unwrap_or(&0);
+                let (last, last_val) = match last_rows[c2] {
+                    Some((last, slot)) => (last, rows[slot * width + db]),
+                    None => (0, max_dist),
+                };
 
                 let sub_cost = if c1 == c2 { 0 } else { self.sub_cost };
-                mat[i1 + 1][i2 + 1] = min4(
-                    mat[i1][i2] + sub_cost,                                    // substitution
-                    mat[i1 + 1][i2] + self.del_cost,                           // deletion
-                    mat[i1][i2 + 1] + self.ins_cost,                           // insertion
-                    mat[last][db] + i1 + i2 - 2 + self.trans_cost - last - db, // transposition
+                row[i2 + 1] = min4(
+                    prev[i2] + sub_cost,                                  // substitution
+                    row[i2] + self.del_cost,                              // deletion
+                    prev[i2 + 1] + self.ins_cost,                         // insertion
+                    last_val + i1 + i2 - 2 + self.trans_cost - last - db, // transposition
                 );
 
                 if c1 == c2 {
@@ -84,58 +120,81 @@ impl DamerauLevenshtein {
                 }
             }
 
-            char_map.insert(c1, i1);
+            if in_s2[c1] {
+                let slot = if let Some((_, slot)) = last_rows[c1] {
+                    slot
+                } else {
+                    rows.resize(rows.len() + width, 0);
+                    rows.len() / width - 1
+                };
+                rows[slot * width..(slot + 1) * width].copy_from_slice(prev);
+                last_rows[c1] = Some((i1, slot));
+            }
         }
 
+        let (last, _) = two_rows(buf, width, l1);
         Result {
             is_distance: true,
-            abs: mat[l1 + 1][l2 + 1],
+            abs: last[l2 + 1],
             max: l1.max(l2),
             len1: l1,
             len2: l2,
         }
     }
 
-    #[allow(clippy::needless_range_loop)]
-    fn get_restricted<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+    /// Replace the elements by ids and run the unrestricted algorithm.
+    fn with_ids<E: Eq + Hash>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
+        let n_ids = element_ids(scratch, s1, s2);
+        let ids1 = core::mem::take(&mut scratch.ids1);
+        let ids2 = core::mem::take(&mut scratch.ids2);
+        let res = self.get_unrestricted(scratch, &ids1, &ids2, n_ids);
+        scratch.ids1 = ids1;
+        scratch.ids2 = ids2;
+        res
+    }
+
+    /// Optimal string alignment. Only the last three rows are kept.
+    fn get_restricted<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
         let l1 = s1.len();
         let l2 = s2.len();
 
-        let mut mat: Vec<Vec<usize>> = vec![vec![0; l2 + 2]; l1 + 2];
-        for i in 0..=l1 {
-            mat[i][0] = i;
-        }
-        for i in 0..=l2 {
-            mat[0][i] = i;
+        let width = l2 + 1;
+        let buf = &mut scratch.rows;
+        reset(buf, width * 3, 0);
+        for (i2, cell) in buf[width..width * 2].iter_mut().enumerate() {
+            *cell = i2;
         }
 
         for (i1, c1) in s1.iter().enumerate() {
+            let (prev2, prev, row) = three_rows(buf, width, i1);
+            row[0] = i1 + 1;
             for (i2, c2) in s2.iter().enumerate() {
                 let sub_cost = if c1 == c2 { 0 } else { self.sub_cost };
-                mat[i1 + 1][i2 + 1] = min3(
-                    mat[i1][i2 + 1] + self.del_cost, // deletion
-                    mat[i1 + 1][i2] + self.ins_cost, // insertion
-                    mat[i1][i2] + sub_cost,          // substitution
+                row[i2 + 1] = min3(
+                    prev[i2 + 1] + self.del_cost, // deletion
+                    row[i2] + self.ins_cost,      // insertion
+                    prev[i2] + sub_cost,          // substitution
                 );
 
                 // transposition
                 if i1 == 0 || i2 == 0 {
                     continue;
-                };
+                }
                 if c1 != &s2[i2 - 1] {
                     continue;
-                };
+                }
                 if &s1[i1 - 1] != c2 {
                     continue;
-                };
+                }
                 let trans_cost = if c1 == c2 { 0 } else { self.trans_cost };
-                mat[i1 + 1][i2 + 1] = mat[i1 + 1][i2 + 1].min(mat[i1 - 1][i2 - 1] + trans_cost);
+                row[i2 + 1] = row[i2 + 1].min(prev2[i2 - 1] + trans_cost);
             }
         }
 
+        let (_, last, _) = three_rows(buf, width, l1);
         Result {
             is_distance: true,
-            abs: mat[l1][l2],
+            abs: last[l2],
             max: l1.max(l2),
             len1: l1,
             len2: l2,
@@ -145,11 +204,52 @@ impl DamerauLevenshtein {
 
 impl Algorithm<usize> for DamerauLevenshtein {
     fn for_vec<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+        self.for_vec_with(&mut Scratch::default(), s1, s2)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<usize> {
+        self.for_str_with(&mut Scratch::default(), s1, s2)
+    }
+}
+
+impl WithScratch<usize> for DamerauLevenshtein {
+    fn for_vec_with<E: Eq + Hash>(
+        &self,
+        scratch: &mut Scratch,
+        s1: &[E],
+        s2: &[E],
+    ) -> Result<usize> {
         if self.restricted {
-            self.get_restricted(s1, s2)
-        } else {
-            self.get_unrestricted(s1, s2)
+            return self.get_restricted(scratch, s1, s2);
         }
+        self.with_ids(scratch, s1, s2)
+    }
+
+    fn for_str_with(&self, scratch: &mut Scratch, s1: &str, s2: &str) -> Result<usize> {
+        if self.restricted {
+            return with_chars(scratch, s1, s2, |scratch, s1, s2| {
+                self.get_restricted(scratch, s1, s2)
+            });
+        }
+        with_chars(scratch, s1, s2, |scratch, s1, s2| {
+            self.with_ids(scratch, s1, s2)
+        })
+    }
+}
+
+/// Split the buffer of three rows into the row before the previous one,
+/// the previous row, and the current row for the step `i` of the algorithm.
+fn three_rows(
+    buf: &mut [usize],
+    width: usize,
+    i: usize,
+) -> (&mut [usize], &mut [usize], &mut [usize]) {
+    let (a, rest) = buf.split_at_mut(width);
+    let (b, c) = rest.split_at_mut(width);
+    match i % 3 {
+        0 => (a, b, c),
+        1 => (b, c, a),
+        _ => (c, a, b),
     }
 }
 
@@ -168,6 +268,7 @@ mod tests {
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
+    use std::collections::HashMap;
 
     #[rstest]
     #[case("", "", 0)]
@@ -221,7 +322,77 @@ mod tests {
         assert!(a.for_str("abcd", "bdac").val() == 3);
     }
 
+    #[test]
+    fn long() {
+        let s1 = "ab".repeat(1000);
+        let s2 = "ba".repeat(1000);
+        assert!(damerau_levenshtein(&s1, &s2) == 2);
+        assert!(damerau_levenshtein_restricted(&s1, &s2) == 2);
+    }
+
+    /// Lowrance-Wagner algorithm with the full matrix.
+    fn reference(a: &DamerauLevenshtein, s1: &[u8], s2: &[u8]) -> usize {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let max_dist = l1 + l2;
+        let mut mat = vec![vec![0; l2 + 2]; l1 + 2];
+        mat[0][0] = max_dist;
+        for i in 0..=l1 {
+            mat[i + 1][0] = max_dist;
+            mat[i + 1][1] = i;
+        }
+        for i in 0..=l2 {
+            mat[0][i + 1] = max_dist;
+            mat[1][i + 1] = i;
+        }
+        let mut last_row: HashMap<u8, usize> = HashMap::new();
+        for i1 in 1..=l1 {
+            let mut db = 0;
+            for i2 in 1..=l2 {
+                let last = last_row.get(&s2[i2 - 1]).copied().unwrap_or(0);
+                let same = s1[i1 - 1] == s2[i2 - 1];
+                let sub_cost = if same { 0 } else { a.sub_cost };
+                mat[i1 + 1][i2 + 1] = min4(
+                    mat[i1][i2] + sub_cost,
+                    mat[i1 + 1][i2] + a.del_cost,
+                    mat[i1][i2 + 1] + a.ins_cost,
+                    mat[last][db] + i1 + i2 - 2 + a.trans_cost - last - db,
+                );
+                if same {
+                    db = i2;
+                }
+            }
+            last_row.insert(s1[i1 - 1], i1);
+        }
+        mat[l1 + 1][l2 + 1]
+    }
+
     proptest! {
+        #[test]
+        fn prop_reference(
+            s1 in proptest::collection::vec(0..4u8, 0..12),
+            s2 in proptest::collection::vec(0..4u8, 0..12),
+            costs in proptest::array::uniform4(1..4usize),
+        ) {
+            let [del_cost, ins_cost, sub_cost, trans_cost] = costs;
+            let a = DamerauLevenshtein {
+                restricted: false,
+                del_cost,
+                ins_cost,
+                sub_cost,
+                trans_cost,
+            };
+            prop_assert_eq!(a.for_vec(&s1, &s2).val(), reference(&a, &s1, &s2));
+        }
+
+        #[test]
+        fn prop_levenshtein(s1 in "[abc]*", s2 in "[abc]*") {
+            let unrestricted = damerau_levenshtein(&s1, &s2);
+            let restricted = damerau_levenshtein_restricted(&s1, &s2);
+            prop_assert!(unrestricted <= restricted);
+            prop_assert!(restricted <= crate::str::levenshtein(&s1, &s2));
+        }
+
         #[test]
         fn prop_default(s1 in ".*", s2 in ".*") {
             let res = damerau_levenshtein(&s1, &s2);
@@ -0,0 +1,272 @@
+//! Editex
+use crate::{Algorithm, Result};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// [Editex] is a phonetic edit distance, a variation of [`Levenshtein`](crate::Levenshtein)
+/// where the cost of an edit depends on how the letters sound.
+///
+/// Replacing a letter with a letter from the same phonetic group (like "D" and "T")
+/// costs less than replacing it with an unrelated letter.
+///
+/// Letter groups are known only for ASCII letters in `for_str`. All other elements
+/// (including other methods of [`Algorithm`]) are compared only on equality.
+///
+/// [Editex]: https://anlp.org/wp-content/uploads/1996/01/Zobel-Dart-1996.pdf
+pub struct Editex {
+    /// The cost of keeping the same letter. Default: 0.
+    pub match_cost: usize,
+
+    /// The cost of replacing a letter with another one from the same phonetic group. Default: 1.
+    pub group_cost: usize,
+
+    /// The cost of replacing a letter with an unrelated one. Default: 2.
+    pub mismatch_cost: usize,
+
+    /// If true, calculate the local variant of Editex where leading letters
+    /// of the first sequence can be skipped for free. Default: false.
+    pub local: bool,
+}
+
+impl Default for Editex {
+    fn default() -> Self {
+        Self {
+            match_cost: 0,
+            group_cost: 1,
+            mismatch_cost: 2,
+            local: false,
+        }
+    }
+}
+
+/// Letters that sound similar.
+const GROUPS: [&[u8]; 10] = [
+    b"AEIOUY", b"BP", b"CKQ", b"DT", b"LR", b"MN", b"GJ", b"FPV", b"SXZ", b"CSZ",
+];
+
+/// Letters that are often silent.
+const UNGROUPED: &[u8] = b"HW";
+
+impl Editex {
+    /// The cost of replacing `c1` with `c2`.
+    fn r_cost(&self, c1: char, c2: char) -> usize {
+        if c1 == c2 {
+            return self.match_cost;
+        }
+        if !c1.is_ascii_uppercase() || !c2.is_ascii_uppercase() {
+            return self.mismatch_cost;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let (c1, c2) = (c1 as u8, c2 as u8);
+        if GROUPS.iter().any(|g| g.contains(&c1) && g.contains(&c2)) {
+            return self.group_cost;
+        }
+        self.mismatch_cost
+    }
+
+    /// The cost of deleting `c` that goes after `prev`.
+    fn d_cost(&self, prev: Option<char>, c: char) -> usize {
+        let prev = prev.unwrap_or(' ');
+        if prev != c && prev.is_ascii() && UNGROUPED.contains(&(prev as u8)) {
+            return self.group_cost;
+        }
+        self.r_cost(prev, c)
+    }
+
+    /// The cost of replacing or deleting elements which don't belong to any group.
+    fn eq_cost<E: Eq>(&self, e1: Option<&E>, e2: &E) -> usize {
+        if e1 == Some(e2) {
+            self.match_cost
+        } else {
+            self.mismatch_cost
+        }
+    }
+
+    fn editex<E, R, D>(&self, s1: &[E], s2: &[E], r_cost: R, d_cost: D) -> Result<usize>
+    where
+        R: Fn(&E, &E) -> usize,
+        D: Fn(Option<&E>, &E) -> usize,
+    {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        // any step of the diagonal path followed by deletions or insertions
+        // costs at most that much, even if matches or groups cost more than mismatches
+        let step = self.mismatch_cost.max(self.group_cost).max(self.match_cost);
+        let max = l1.max(l2) * step;
+        if l1 == 0 || l2 == 0 {
+            return Result {
+                abs: l1.max(l2) * self.mismatch_cost,
+                is_distance: true,
+                max,
+                len1: l1,
+                len2: l2,
+            };
+        }
+
+        // the cost of deleting each element of the sequences
+        let del1: Vec<usize> = (0..l1)
+            .map(|i| d_cost(i.checked_sub(1).map(|j| &s1[j]), &s1[i]))
+            .collect();
+        let del2: Vec<usize> = (0..l2)
+            .map(|i| d_cost(i.checked_sub(1).map(|j| &s2[j]), &s2[i]))
+            .collect();
+
+        let mut prev_row: Vec<usize> = Vec::with_capacity(l2 + 1);
+        prev_row.push(0);
+        for (j, cost) in del2.iter().enumerate() {
+            prev_row.push(prev_row[j] + cost);
+        }
+        let mut row = prev_row.clone();
+        for (i, c1) in s1.iter().enumerate() {
+            row[0] = if self.local { 0 } else { prev_row[0] + del1[i] };
+            for (j, c2) in s2.iter().enumerate() {
+                row[j + 1] = (prev_row[j + 1] + del1[i])
+                    .min(row[j] + del2[j])
+                    .min(prev_row[j] + r_cost(c1, c2));
+            }
+            core::mem::swap(&mut row, &mut prev_row);
+        }
+        Result {
+            abs: prev_row[l2],
+            is_distance: true,
+            max,
+            len1: l1,
+            len2: l2,
+        }
+    }
+}
+
+impl Algorithm<usize> for Editex {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<usize>
+    where
+        E: Eq + Hash,
+    {
+        self.editex(
+            s1,
+            s2,
+            |e1, e2| self.eq_cost(Some(e1), e2),
+            |e1, e2| self.eq_cost(e1, e2),
+        )
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<usize> {
+        let s1: Vec<char> = s1.chars().map(|c| c.to_ascii_uppercase()).collect();
+        let s2: Vec<char> = s2.chars().map(|c| c.to_ascii_uppercase()).collect();
+        self.editex(
+            &s1,
+            &s2,
+            |c1, c2| self.r_cost(*c1, *c2),
+            |c1, c2| self.d_cost(c1.copied(), *c2),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::editex;
+    use crate::{Algorithm, Editex};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // parity with textdistance
+    #[case("", "", 0)]
+    #[case("nelson", "", 12)]
+    #[case("", "neilsen", 14)]
+    #[case("ab", "a", 2)]
+    #[case("ab", "c", 4)]
+    #[case("ALIE", "ALI", 1)]
+    #[case("", "MARTHA", 12)]
+    // parity with abydos
+    #[case("niall", "neal", 1)]
+    #[case("neal", "niall", 1)]
+    #[case("niall", "nihal", 2)]
+    #[case("nihal", "niall", 2)]
+    #[case("neal", "nihl", 3)]
+    #[case("nihl", "neal", 3)]
+    #[case("cat", "hat", 2)]
+    #[case("Niall", "Neil", 2)]
+    #[case("aluminum", "Catalan", 12)]
+    #[case("ATCG", "TAGC", 6)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let act = editex(s1, s2);
+        assert!(act == exp, "editex({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("nelson", "", 12)]
+    #[case("", "neilsen", 14)]
+    #[case("ab", "a", 2)]
+    #[case("ab", "c", 2)]
+    #[case("ALIE", "ALI", 1)]
+    #[case("", "MARTHA", 12)]
+    #[case("niall", "neal", 1)]
+    #[case("neal", "niall", 1)]
+    #[case("aluminum", "Catalan", 10)]
+    #[case("ATCG", "TAGC", 4)]
+    fn local(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let a = Editex {
+            local: true,
+            ..Default::default()
+        };
+        assert!(a.for_str(s1, s2).val() == exp);
+    }
+
+    #[test]
+    fn costs() {
+        let a = Editex {
+            match_cost: 0,
+            group_cost: 1,
+            mismatch_cost: 3,
+            local: false,
+        };
+        assert!(a.for_str("dog", "tog").val() == 1);
+        assert!(a.for_str("dog", "log").val() == 3);
+        assert!(a.for_str("dog", "dog").max == 9);
+    }
+
+    #[test]
+    fn expensive_group() {
+        let a = Editex {
+            group_cost: 5,
+            ..Default::default()
+        };
+        let res = a.for_str("d", "t");
+        assert!(res.val() == 4);
+        assert!(res.max == 5);
+        assert!(a.for_str("", "dt").max == 10);
+        assert!(a.for_str("dog", "tog").nval() <= 1.);
+    }
+
+    #[test]
+    fn generic() {
+        let a = Editex::default();
+        assert!(a.for_vec(&[1, 2, 3], &[1, 2, 3]).val() == 0);
+        assert!(a.for_vec(&[1, 2, 3], &[1, 4, 3]).val() == 2);
+        assert!(a.for_vec(&[1, 2, 3], &[1, 3]).val() == 2);
+        assert!(a.for_words("dog cat", "dog hat").val() == 2);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_levenshtein(s1 in ".*", s2 in ".*") {
+            let lev = crate::str::levenshtein(&s1, &s2);
+            prop_assert!(editex(&s1, &s2) <= lev * 2);
+        }
+
+        #[test]
+        fn prop_max(
+            s1 in "[a-z]*",
+            s2 in "[a-z]*",
+            costs in proptest::array::uniform3(0..5usize),
+            local: bool,
+        ) {
+            let [match_cost, group_cost, mismatch_cost] = costs;
+            let a = Editex { match_cost, group_cost, mismatch_cost, local };
+            let res = a.for_str(&s1, &s2);
+            prop_assert!(res.val() <= res.max);
+        }
+    }
+}
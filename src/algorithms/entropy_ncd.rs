@@ -18,6 +18,10 @@ pub struct EntropyNCD {
     /// so that the entropy is never zero. It accounts for all real-world compression
     /// algorithms having a fixed header with metadata. Default: 1.
     pub correction: f64,
+
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
 }
 
 impl Default for EntropyNCD {
@@ -25,6 +29,7 @@ impl Default for EntropyNCD {
         Self {
             base: 2,
             correction: 1.,
+            as_set: false,
         }
     }
 }
@@ -49,8 +54,8 @@ impl Algorithm<f64> for EntropyNCD {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let cm = c1.merge(&c2);
         let cl1 = self.compress(&c1);
         let cl2 = self.compress(&c2);
@@ -75,6 +80,7 @@ mod tests {
     use super::Counter;
     use super::EntropyNCD;
     use crate::str::entropy_ncd;
+    use crate::Algorithm;
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
@@ -115,6 +121,16 @@ mod tests {
         assert!(ok, "compress({}) is {}, not {}", s, act, exp);
     }
 
+    #[test]
+    fn as_set() {
+        let a = EntropyNCD {
+            as_set: true,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 0.));
+        assert!(EntropyNCD::default().for_str("aaab", "ab").nval() > 0.);
+    }
+
     proptest! {
         #[test]
         fn compress_idempotency(s in ".+") {
@@ -0,0 +1,54 @@
+//! Euclidean distance
+#![cfg(feature = "std")]
+use crate::{Algorithm, Minkowski, Result};
+
+/// [Euclidean distance] (aka L2 distance) is the square root of the sum of squared differences of element counts.
+///
+/// It is [`Minkowski`] distance with p=2,
+/// see its documentation for how the distance is normalized.
+///
+/// [Euclidean distance]: https://en.wikipedia.org/wiki/Euclidean_distance
+#[derive(Default)]
+pub struct Euclidean {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Euclidean {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let a = Minkowski {
+            p: 2.,
+            as_set: self.as_set,
+        };
+        a.for_iter(s1, s2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::euclidean;
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("nelson", "", 1.)]
+    #[case("abc", "cba", 0.)]
+    #[case("abc", "def", 1.)]
+    #[case("test", "text", 0.408_248)]
+    #[case("aab", "abb", 0.447_214)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = euclidean(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "euclidean({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+}
@@ -0,0 +1,388 @@
+//! Eudex phonetic hash
+use crate::{Algorithm, Result};
+use core::hash::Hash;
+
+/// [Eudex] is a phonetic hash. The distance is the number of bits that differ
+/// in the hashes of two strings, just like [`Hamming`](crate::Hamming) for bits.
+///
+/// The hash is a 64-bit number where each byte describes phonetic properties
+/// (like nasal, plosive, labial, etc.) of one sound. The first byte is for
+/// the first letter of the word, and the rest are for the following consonants
+/// and vowels, skipping the sounds that are the same as the previous one.
+/// So, similar sounding words have a small number of different bits,
+/// and the distance between two words can be calculated in a constant time
+/// from their precomputed hashes.
+///
+///     use textdistance::{Algorithm, Eudex};
+///     let a = Eudex::default();
+///     assert!(a.for_str("maier", "meyer").val() == 0);
+///     assert!(a.for_str("jumpo", "jumbo").val() == 5);
+///     assert!(Eudex::hash("Repert") == Eudex::hash("ropert"));
+///
+/// Eudex is designed for single words in Latin alphabets. Letters from "a" to "z"
+/// and Latin-1 letters (like "ß" or "é") have sounds, other characters are ignored,
+/// and so are the letters past the first 8 distinct sounds. In all methods of [`Algorithm`] except `for_str`,
+/// the elements cannot be hashed, so the distance is 0 for equal sequences and maximal otherwise.
+///
+/// [Eudex]: https://github.com/ticki/eudex
+#[derive(Default)]
+pub struct Eudex {
+    /// If true, the differences in the first letters have higher weight
+    /// than the differences at the end of the word. The weights for the bits
+    /// of each byte (starting from the last one) are Fibonacci numbers:
+    /// 1, 2, 3, 5, 8, 13, 21, 34. Default: false.
+    pub weighted: bool,
+}
+
+/// The sound table for the letters from "a" to "z".
+///
+/// Each bit describes a property of the sound, from the highest to the lowest:
+/// confident (hard to misspell), labial, liquid, dental, plosive, fricative, nasal,
+/// discriminant. Vowels are 0 (open) and 1 (close).
+const PHONES: [u8; 26] = [
+    0,          // a
+    0b01001000, // b
+    0b00001100, // c
+    0b00011000, // d
+    0,          // e
+    0b01000100, // f
+    0b00001000, // g
+    0b00000100, // h
+    1,          // i
+    0b00000101, // j
+    0b00001001, // k
+    0b10100000, // l
+    0b00000010, // m
+    0b00010010, // n
+    0,          // o
+    0b01001001, // p
+    0b10101000, // q
+    0b10100001, // r
+    0b00010100, // s
+    0b00011101, // t
+    1,          // u
+    0b01000101, // v
+    0b00000000, // w
+    0b10000100, // x
+    1,          // y
+    0b10010100, // z
+];
+
+/// The sound table for Latin-1 letters starting from "ß".
+const PHONES_C1: [u8; 33] = [
+    PHONES[18] ^ 1, // ß
+    0,              // à
+    0,              // á
+    0,              // â
+    0,              // ã
+    0,              // ä
+    1,              // å
+    0,              // æ
+    PHONES[25] ^ 1, // ç
+    1,              // è
+    1,              // é
+    1,              // ê
+    1,              // ë
+    1,              // ì
+    1,              // í
+    1,              // î
+    1,              // ï
+    0b00010101,     // ð
+    0b00010111,     // ñ
+    0,              // ò
+    0,              // ó
+    0,              // ô
+    0,              // õ
+    1,              // ö
+    !0,             // ÷
+    1,              // ø
+    1,              // ù
+    1,              // ú
+    1,              // û
+    1,              // ü
+    1,              // ý
+    0b00010101,     // þ
+    1,              // ÿ
+];
+
+/// The injective sound table for the first letter, from "a" to "z".
+///
+/// For vowels, the highest bit is set and the rest describe the vowel.
+/// For consonants, it is [`PHONES`] shifted to the right.
+const INJECTIVE_PHONES: [u8; 26] = [
+    0b10000100, // a
+    0b00100100, // b
+    0b00000110, // c
+    0b00001100, // d
+    0b11011000, // e
+    0b00100010, // f
+    0b00000100, // g
+    0b00000010, // h
+    0b11111000, // i
+    0b00000011, // j
+    0b00000101, // k
+    0b01010000, // l
+    0b00000001, // m
+    0b00001001, // n
+    0b10010100, // o
+    0b00100101, // p
+    0b01010100, // q
+    0b01010001, // r
+    0b00001010, // s
+    0b00001110, // t
+    0b11100000, // u
+    0b00100011, // v
+    0b00000000, // w
+    0b01000010, // x
+    0b11100100, // y
+    0b01001010, // z
+];
+
+/// The injective sound table for the first letter, for Latin-1 letters starting from "ß".
+const INJECTIVE_PHONES_C1: [u8; 33] = [
+    INJECTIVE_PHONES[18] ^ 1, // ß
+    INJECTIVE_PHONES[0] ^ 1,  // à
+    INJECTIVE_PHONES[0] ^ 1,  // á
+    0b10000000,               // â
+    0b10000110,               // ã
+    0b10100110,               // ä
+    0b11000010,               // å
+    0b10100111,               // æ
+    0b01010100,               // ç
+    INJECTIVE_PHONES[4] ^ 1,  // è
+    INJECTIVE_PHONES[4] ^ 1,  // é
+    INJECTIVE_PHONES[4] ^ 1,  // ê
+    0b11000110,               // ë
+    INJECTIVE_PHONES[8] ^ 1,  // ì
+    INJECTIVE_PHONES[8] ^ 1,  // í
+    INJECTIVE_PHONES[8] ^ 1,  // î
+    INJECTIVE_PHONES[8] ^ 1,  // ï
+    0b00001011,               // ð
+    0b00001011,               // ñ
+    INJECTIVE_PHONES[14] ^ 1, // ò
+    INJECTIVE_PHONES[14] ^ 1, // ó
+    INJECTIVE_PHONES[14] ^ 1, // ô
+    INJECTIVE_PHONES[14] ^ 1, // õ
+    0b11011100,               // ö
+    !0,                       // ÷
+    0b11011101,               // ø
+    INJECTIVE_PHONES[20] ^ 1, // ù
+    INJECTIVE_PHONES[20] ^ 1, // ú
+    INJECTIVE_PHONES[20] ^ 1, // û
+    INJECTIVE_PHONES[24] ^ 1, // ü
+    INJECTIVE_PHONES[24] ^ 1, // ý
+    0b00001011,               // þ
+    INJECTIVE_PHONES[24] ^ 1, // ÿ
+];
+
+/// The weights of bits in each byte of the hash difference, starting from the lowest byte.
+const WEIGHTS: [u32; 8] = [1, 2, 3, 5, 8, 13, 21, 34];
+
+/// Look up the char (case-insensitive) in the table for "a" to "z" or for Latin-1 letters.
+fn lookup(table: &[u8; 26], table_c1: &[u8; 33], c: char) -> Option<u8> {
+    let c = u8::try_from(c).ok()? | 32;
+    if c.is_ascii_lowercase() {
+        return Some(table[usize::from(c - b'a')]);
+    }
+    // "À" to "Þ" are lowercased into "à" to "þ" by the same bit, "ß" and "ÿ" have it set
+    if c >= 0xDF {
+        return Some(table_c1[usize::from(c - 0xDF)]);
+    }
+    None
+}
+
+impl Eudex {
+    /// Calculate the Eudex hash for the given word.
+    ///
+    /// Words with the same hash sound the same. Characters other than letters are ignored.
+    ///
+    ///     use textdistance::Eudex;
+    ///     assert!(Eudex::hash("Repert") == Eudex::hash("ropert"));
+    ///     assert!(Eudex::hash("Rupert") != Eudex::hash("Robert"));
+    ///
+    pub fn hash(s: &str) -> u64 {
+        let mut chars = s.chars();
+        let first = chars.next().map_or(0, |c| {
+            lookup(&INJECTIVE_PHONES, &INJECTIVE_PHONES_C1, c).unwrap_or(0)
+        });
+        let mut res: u64 = 0;
+        let mut pushed = 0;
+        for c in chars {
+            if pushed == 8 {
+                break;
+            }
+            let Some(phone) = lookup(&PHONES, &PHONES_C1, c) else {
+                continue;
+            };
+            // skip the sound if its discriminant is the same as for the previous one
+            #[allow(clippy::cast_possible_truncation)]
+            if phone & 1 != (res as u8) & 1 {
+                res = (res << 8) | u64::from(phone);
+                pushed += 1;
+            }
+        }
+        res | (u64::from(first) << 56)
+    }
+
+    /// The distance between two hashes.
+    fn dist(&self, h1: u64, h2: u64) -> usize {
+        let xor = h1 ^ h2;
+        if !self.weighted {
+            return xor.count_ones() as usize;
+        }
+        let dist: u32 = xor
+            .to_le_bytes()
+            .iter()
+            .zip(WEIGHTS)
+            .map(|(b, w)| b.count_ones() * w)
+            .sum();
+        dist as usize
+    }
+
+    fn max(&self) -> usize {
+        if self.weighted {
+            8 * WEIGHTS.iter().sum::<u32>() as usize
+        } else {
+            64
+        }
+    }
+}
+
+impl Algorithm<usize> for Eudex {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<usize>
+    where
+        E: Eq + Hash,
+    {
+        let max = self.max();
+        Result {
+            abs: if s1 == s2 { 0 } else { max },
+            is_distance: true,
+            max,
+            len1: s1.len(),
+            len2: s2.len(),
+        }
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<usize> {
+        Result {
+            abs: self.dist(Self::hash(s1), Self::hash(s2)),
+            is_distance: true,
+            max: self.max(),
+            len1: s1.chars().count(),
+            len2: s2.chars().count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::eudex;
+    use crate::{Algorithm, Eudex};
+    use assert2::assert;
+    use rstest::rstest;
+
+    // parity with the eudex crate
+    #[rstest]
+    #[case("JAva", "jAva")]
+    #[case("co!mputer", "computer")]
+    #[case("comp-uter", "computer")]
+    #[case("comp@u#te?r", "computer")]
+    #[case("lal", "lel")]
+    #[case("rindom", "ryndom")]
+    #[case("riiiindom", "ryyyyyndom")]
+    #[case("riyiyiiindom", "ryyyyyndom")]
+    #[case("triggered", "TRIGGERED")]
+    #[case("repert", "ropert")]
+    fn same_hash(#[case] s1: &str, #[case] s2: &str) {
+        assert!(Eudex::hash(s1) == Eudex::hash(s2));
+        assert!(eudex(s1, s2) == 0);
+    }
+
+    #[rstest]
+    #[case("reddit", "eddit")]
+    #[case("lol", "lulz")]
+    #[case("ijava", "java")]
+    #[case("jiva", "java")]
+    #[case("jesus", "iesus")]
+    #[case("aesus", "iesus")]
+    #[case("iesus", "yesus")]
+    #[case("rupirt", "ropert")]
+    #[case("ripert", "ropyrt")]
+    #[case("rrr", "rraaaa")]
+    #[case("randomal", "randomai")]
+    fn different_hash(#[case] s1: &str, #[case] s2: &str) {
+        assert!(Eudex::hash(s1) != Eudex::hash(s2));
+        assert!(eudex(s1, s2) > 0);
+    }
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("a", "b", 2)]
+    #[case("maier", "meyer", 0)]
+    #[case("jumpo", "jumbo", 5)]
+    #[case("what", "wat", 0)]
+    #[case("horse", "norse", 3)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let act = eudex(s1, s2);
+        assert!(act == exp, "eudex({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("yay", "yuy")]
+    #[case("what", "wat")]
+    #[case("jesus", "jeuses")]
+    #[case("", "")]
+    #[case("jumpo", "jumbo")]
+    #[case("lol", "lulz")]
+    #[case("goth", "god")]
+    #[case("maier", "meyer")]
+    #[case("java", "jiva")]
+    #[case("möier", "meyer")]
+    #[case("fümlaut", "fymlaut")]
+    fn similar(#[case] s1: &str, #[case] s2: &str) {
+        let a = Eudex { weighted: true };
+        assert!(a.for_str(s1, s2).val() < 15);
+    }
+
+    #[rstest]
+    #[case("youtube", "reddit")]
+    #[case("yet", "vet")]
+    #[case("hacker", "4chan")]
+    #[case("awesome", "me")]
+    #[case("prisco", "vkisco")]
+    #[case("no", "go")]
+    #[case("horse", "norse")]
+    #[case("nice", "mice")]
+    fn not_similar(#[case] s1: &str, #[case] s2: &str) {
+        let a = Eudex { weighted: true };
+        assert!(a.for_str(s1, s2).val() >= 15);
+    }
+
+    #[test]
+    fn weighted() {
+        let a = Eudex { weighted: true };
+        assert!(a.for_str("", "").max == 696);
+        let d1 = a.for_str("lizzard", "wizzard").val();
+        let d2 = a.for_str("rick", "rolled").val();
+        assert!(d1 > d2);
+        let d1 = a.for_str("trump", "drumpf").val();
+        let d2 = a.for_str("gangam", "style").val();
+        assert!(d1 < d2);
+    }
+
+    #[test]
+    fn latin1() {
+        assert!(Eudex::hash("straße") != Eudex::hash("strae"));
+        assert!(Eudex::hash("straße") == Eudex::hash("STRAßE"));
+        assert!(Eudex::hash("ÉCOLE") == Eudex::hash("école"));
+        assert!(Eudex::hash("école") != Eudex::hash("ecole"));
+        assert!(Eudex::hash("€uro") == Eudex::hash("€ur-o"));
+    }
+
+    #[test]
+    fn generic() {
+        let a = Eudex::default();
+        assert!(a.for_vec(&[1, 2], &[1, 2]).val() == 0);
+        assert!(a.for_vec(&[1, 2], &[1, 3]).val() == 64);
+    }
+}
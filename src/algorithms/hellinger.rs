@@ -0,0 +1,75 @@
+//! Hellinger distance
+#![cfg(feature = "std")]
+use crate::{Algorithm, Bhattacharyya, Result};
+
+/// [Hellinger distance] between distributions of elements in two sequences.
+///
+/// It is `sqrt(1 - BC)` where BC is [`Bhattacharyya`] coefficient.
+/// Unlike Bhattacharyya distance, it is a metric and is bounded by 1.0.
+///
+///     use textdistance::{Algorithm, Hellinger};
+///     let a = Hellinger::default();
+///     assert!(a.for_words("the cat sat", "the cat sat").nval() == 0.);
+///     assert!(a.for_words("the cat sat", "a dog ran").nval() == 1.);
+///
+/// [Hellinger distance]: https://en.wikipedia.org/wiki/Hellinger_distance
+#[derive(Default)]
+pub struct Hellinger {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Hellinger {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let bc = Bhattacharyya {
+            as_set: self.as_set,
+        }
+        .for_iter(s1, s2);
+        Result {
+            abs: (1. - bc.nval()).max(0.).sqrt(),
+            is_distance: true,
+            ..bc
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::hellinger;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("", "abc", 1.)]
+    #[case("test", "test", 0.)]
+    #[case("abc", "cba", 0.)]
+    #[case("aaa", "bbb", 1.)]
+    #[case("test", "text", 0.5)]
+    #[case("aab", "abb", 0.239_146)]
+    #[case("nelson", "neilsen", 0.405_671)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = hellinger(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "hellinger({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = hellinger(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, hellinger(&s2, &s1)));
+        }
+    }
+}
@@ -12,7 +12,11 @@ use crate::{Algorithm, Result};
 ///
 /// [Jaccard similarity]: https://en.wikipedia.org/wiki/Jaccard_index
 #[derive(Default)]
-pub struct Jaccard {}
+pub struct Jaccard {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
 
 impl Algorithm<f64> for Jaccard {
     fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
@@ -20,8 +24,8 @@ impl Algorithm<f64> for Jaccard {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let uc = c1.union_count(&c2);
         let res = if uc == 0 {
             1.
@@ -41,7 +45,7 @@ impl Algorithm<f64> for Jaccard {
 
 #[cfg(test)]
 mod tests {
-    use crate::str::jaccard;
+    use crate::str::{jaccard, jaccard_set};
     use assert2::assert;
     use rstest::rstest;
 
@@ -64,4 +68,17 @@ mod tests {
         let ok = is_close(act, exp);
         assert!(ok, "jaccard({}, {}) is {}, not {}", s1, s2, act, exp);
     }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("aaab", "ab", 1.)]
+    // parity with textdistance (as_set=True)
+    #[case("test", "text", 0.5)]
+    #[case("nelson", "neilsen", 4. / 6.)]
+    fn function_str_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = jaccard_set(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "jaccard_set({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
 }
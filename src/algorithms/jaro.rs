@@ -1,73 +1,154 @@
 //! Jaro similarity
-use crate::{Algorithm, Result};
-use alloc::vec;
+use crate::scratch::reset;
+use crate::{Algorithm, Result, Scratch, WithScratch};
+use alloc::vec::Vec;
 
 /// [Jaro similarity] is calculated based on the number of transpositions to turn one string into the other.
 ///
 /// The metric is always normalized on the interval from 0.0 to 1.0.
 ///
+/// Equal elements are considered matching only if they are not too far from each other,
+/// see [`JaroWindow`].
+///
+///     use textdistance::{Algorithm, Jaro, JaroWindow};
+///     let a = Jaro {
+///         window: JaroWindow::Unlimited,
+///     };
+///     assert!(a.for_str("abcdef", "defabc").nval() > Jaro::default().for_str("abcdef", "defabc").nval());
+///
 /// See also [`JaroWinkler`](crate::JaroWinkler).
 ///
 /// [Jaro similarity]: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance#Jaro_similarity
 #[derive(Default)]
-pub struct Jaro {}
+pub struct Jaro {
+    /// How far from each other equal elements can be to be considered matching.
+    /// Default: [`JaroWindow::Proportional(0.5)`], the classic Jaro window.
+    ///
+    /// [`JaroWindow::Proportional(0.5)`]: JaroWindow::Proportional
+    pub window: JaroWindow,
+}
 
-impl Algorithm<f64> for Jaro {
-    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<f64> {
-        let l1 = s1.len();
-        let l2 = s2.len();
+/// The maximum distance between matching elements in [`Jaro`].
+pub enum JaroWindow {
+    /// At most the given number of positions.
+    Absolute(usize),
 
-        if l1 == 0 || l2 == 0 {
-            let result = if l1 == 0 && l2 == 0 { 1. } else { 0. };
-            return Result {
-                abs: result,
-                is_distance: false,
-                max: 1.0,
-                len1: l1,
-                len2: l2,
-            };
+    /// At most `max(l1, l2) * ratio - 1` positions (rounded down),
+    /// where `l1` and `l2` are the lengths of the sequences.
+    ///
+    /// The classic Jaro window is `Proportional(0.5)`.
+    Proportional(f64),
+
+    /// Elements match wherever they are.
+    Unlimited,
+}
+
+impl Default for JaroWindow {
+    fn default() -> Self {
+        Self::Proportional(0.5)
+    }
+}
+
+impl JaroWindow {
+    /// The maximum distance between matching elements of sequences with the given lengths.
+    fn size(&self, l1: usize, l2: usize) -> usize {
+        match self {
+            Self::Absolute(size) => *size,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Self::Proportional(ratio) => ((l1.max(l2) as f64 * ratio) as usize).saturating_sub(1),
+            Self::Unlimited => usize::MAX,
         }
-        if l1 == 1 && l2 == 1 {
-            let result = if s1[0] == s2[0] { 1.0 } else { 0.0 };
-            return Result {
-                abs: result,
-                is_distance: false,
-                max: 1.0,
-                len1: l1,
-                len2: l2,
+    }
+}
+
+/// Elements of two sequences matched by [`Jaro`].
+pub(crate) struct Matches {
+    /// Which elements of the first sequence have a match in the second one.
+    pub flags1: Vec<bool>,
+
+    /// Which elements of the second sequence have a match in the first one.
+    pub flags2: Vec<bool>,
+
+    /// How many elements are matched.
+    pub count: usize,
+}
+
+/// Matches of sequences of up to 64 elements, stored as bit masks.
+struct BitMatches {
+    flags1: u64,
+    flags2: u64,
+    count: usize,
+    transpositions: usize,
+}
+
+impl Jaro {
+    /// The range of positions in the second sequence that can match
+    /// the element at the given position in the first one.
+    fn bounds(window: usize, i: usize, l2: usize) -> Option<(usize, usize)> {
+        let min_bound = i.saturating_sub(window);
+        let max_bound = usize::min(l2.checked_sub(1)?, i.saturating_add(window));
+        (min_bound <= max_bound).then_some((min_bound, max_bound))
+    }
+
+    /// Match each element of the first sequence with the first unmatched equal
+    /// element of the second sequence within the window.
+    pub(crate) fn matches<E: Eq>(&self, s1: &[E], s2: &[E]) -> Matches {
+        if s1.len() <= 64 && s2.len() <= 64 {
+            let m = self.bit_matches(s1, s2);
+            return Matches {
+                flags1: (0..s1.len()).map(|i| m.flags1 >> i & 1 == 1).collect(),
+                flags2: (0..s2.len()).map(|j| m.flags2 >> j & 1 == 1).collect(),
+                count: m.count,
             };
         }
+        self.vec_matches(s1, s2)
+    }
 
-        let search_range = l1.max(l2) / 2 - 1;
+    /// Matching for sequences of any length, visiting only the window of each element.
+    fn vec_matches<E: Eq>(&self, s1: &[E], s2: &[E]) -> Matches {
+        let mut flags1 = Vec::new();
+        let mut flags2 = Vec::new();
+        let (count, _) = self.fill_matches(s1, s2, &mut flags1, &mut flags2);
+        Matches {
+            flags1,
+            flags2,
+            count,
+        }
+    }
 
-        let mut s2_consumed = vec![false; l2];
-        let mut matches: usize = 0;
+    /// Fill the flags of matched elements, return the number of matches and
+    /// the number of matched elements of the first sequence that have a match
+    /// earlier in the second sequence than the previous matched element.
+    fn fill_matches<E: Eq>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        flags1: &mut Vec<bool>,
+        flags2: &mut Vec<bool>,
+    ) -> (usize, usize) {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let window = self.window.size(l1, l2);
 
-        let mut n_trans = 0.;
+        reset(flags1, l1, false);
+        reset(flags2, l2, false);
+        let mut count: usize = 0;
+
+        let mut transpositions = 0;
         let mut b_match_index = 0;
 
         for (i, a_elem) in s1.iter().enumerate() {
-            let min_bound =
-            // prevent integer wrapping
-            if i > search_range {
-                i - search_range
-            } else {
-                0
-            };
-
-            let max_bound = usize::min(l2 - 1, i + search_range);
-
-            if min_bound > max_bound {
+            let Some((min_bound, max_bound)) = Self::bounds(window, i, l2) else {
                 continue;
-            }
-
-            for (j, b_elem) in s2.iter().enumerate() {
-                if min_bound <= j && j <= max_bound && a_elem == b_elem && !s2_consumed[j] {
-                    s2_consumed[j] = true;
-                    matches += 1;
+            };
+            for j in min_bound..=max_bound {
+                if !flags2[j] && *a_elem == s2[j] {
+                    flags1[i] = true;
+                    flags2[j] = true;
+                    count += 1;
 
                     if j < b_match_index {
-                        n_trans += 1.;
+                        transpositions += 1;
                     }
                     b_match_index = j;
 
@@ -75,11 +156,111 @@ impl Algorithm<f64> for Jaro {
                 }
             }
         }
+        (count, transpositions)
+    }
+
+    /// Bit-parallel matching for sequences of up to 64 elements.
+    ///
+    /// The positions of each distinct element of `s2` are collected into a mask once.
+    /// Then, for each element of `s1`, the unmatched equal elements in the window
+    /// are found by intersecting its mask with the window mask, without visiting the window.
+    fn bit_matches<E: Eq>(&self, s1: &[E], s2: &[E]) -> BitMatches {
+        debug_assert!(s1.len() <= 64 && s2.len() <= 64);
+        let l2 = s2.len();
+        let window = self.window.size(s1.len(), l2);
+
+        let mut m = BitMatches {
+            flags1: 0,
+            flags2: 0,
+            count: 0,
+            transpositions: 0,
+        };
+        let mut b_match_index = 0;
+
+        // the first position and the mask of all positions of each distinct element of s2,
+        // on the stack to not allocate
+        let mut all_masks = [(0, 0); 64];
+        let mut distinct = 0;
+        for (j, b_elem) in s2.iter().enumerate() {
+            let masks = &mut all_masks[..distinct];
+            if let Some((_, mask)) = masks.iter_mut().find(|(first, _)| s2[*first] == *b_elem) {
+                *mask |= 1 << j;
+            } else {
+                all_masks[distinct] = (j, 1 << j);
+                distinct += 1;
+            }
+        }
+        let masks = &all_masks[..distinct];
+
+        for (i, a_elem) in s1.iter().enumerate() {
+            let Some((min_bound, max_bound)) = Self::bounds(window, i, l2) else {
+                continue;
+            };
+            let Some((_, equal)) = masks.iter().find(|(first, _)| s2[*first] == *a_elem) else {
+                continue;
+            };
+            let in_window = (u64::MAX >> (63 - max_bound)) & (u64::MAX << min_bound);
+            let free = equal & in_window & !m.flags2;
+            if free != 0 {
+                let j = free.trailing_zeros() as usize;
+                m.flags1 |= 1 << i;
+                m.flags2 |= 1 << j;
+                m.count += 1;
+
+                if j < b_match_index {
+                    m.transpositions += 1;
+                }
+                b_match_index = j;
+            }
+        }
+        m
+    }
+
+    /// The number of matches and transpositions.
+    ///
+    /// The flags are used as a buffer for sequences longer than 64 elements.
+    fn count_matches<E: Eq>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        flags1: &mut Vec<bool>,
+        flags2: &mut Vec<bool>,
+    ) -> (usize, usize) {
+        if s1.len() <= 64 && s2.len() <= 64 {
+            let m = self.bit_matches(s1, s2);
+            (m.count, m.transpositions)
+        } else {
+            self.fill_matches(s1, s2, flags1, flags2)
+        }
+    }
+
+    fn jaro<E: Eq>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        flags1: &mut Vec<bool>,
+        flags2: &mut Vec<bool>,
+    ) -> Result<f64> {
+        let l1 = s1.len();
+        let l2 = s2.len();
 
+        if l1 == 0 || l2 == 0 {
+            let result = if l1 == 0 && l2 == 0 { 1. } else { 0. };
+            return Result {
+                abs: result,
+                is_distance: false,
+                max: 1.0,
+                len1: l1,
+                len2: l2,
+            };
+        }
+
+        let (matches, n_trans) = self.count_matches(s1, s2, flags1, flags2);
         let result = if matches == 0 {
             0.
         } else {
             let ms = matches as f64;
+            let n_trans = n_trans as f64;
             ((ms / l1 as f64) + (ms / l2 as f64) + ((ms - n_trans) / ms)) / 3.
         };
 
@@ -93,10 +274,24 @@ impl Algorithm<f64> for Jaro {
     }
 }
 
+impl Algorithm<f64> for Jaro {
+    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<f64> {
+        self.jaro(s1, s2, &mut Vec::new(), &mut Vec::new())
+    }
+}
+
+impl WithScratch<f64> for Jaro {
+    fn for_vec_with<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<f64> {
+        self.jaro(s1, s2, &mut scratch.flags1, &mut scratch.flags2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::str::jaro;
     use assert2::assert;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     fn is_close(a: f64, b: f64) -> bool {
@@ -121,4 +316,62 @@ mod tests {
         let ok = is_close(act, exp);
         assert!(ok, "jaro({}, {}) is {}, not {}", s1, s2, act, exp);
     }
+
+    #[rstest]
+    #[case(JaroWindow::default(), "abcdef", "defabc", 0.)]
+    #[case(JaroWindow::Absolute(3), "abcdef", "defabc", 0.944_444)]
+    #[case(JaroWindow::Absolute(0), "abcd", "abdc", 0.666_667)]
+    #[case(JaroWindow::Proportional(1.), "abcdef", "defabc", 0.944_444)]
+    #[case(JaroWindow::Proportional(0.), "ab", "ab", 1.)]
+    #[case(JaroWindow::Unlimited, "abcdef", "defabc", 0.944_444)]
+    #[case(JaroWindow::Unlimited, "a", "bbbbbbbba", 0.703_704)]
+    fn window_size(
+        #[case] window: JaroWindow,
+        #[case] s1: &str,
+        #[case] s2: &str,
+        #[case] exp: f64,
+    ) {
+        let a = Jaro { window };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "jaro({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+
+    #[test]
+    fn long() {
+        let s1 = "ab".repeat(50);
+        let s2 = "ba".repeat(50);
+        assert!(is_close(jaro(&s1, &s2), 0.833_333));
+        assert!(is_close(jaro(&s1, &s1), 1.));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_bit_matches(s1 in "[abc]{0,64}", s2 in "[abc]{0,64}", window in 0..70usize) {
+            let s1: Vec<char> = s1.chars().collect();
+            let s2: Vec<char> = s2.chars().collect();
+            for window in [JaroWindow::default(), JaroWindow::Absolute(window), JaroWindow::Unlimited] {
+                let a = Jaro { window };
+                let exp = a.vec_matches(&s1, &s2);
+                let act = a.matches(&s1, &s2);
+                prop_assert!(act.flags1 == exp.flags1);
+                prop_assert!(act.flags2 == exp.flags2);
+                prop_assert!(act.count == exp.count);
+                let (_, exp_trans) = a.fill_matches(&s1, &s2, &mut Vec::new(), &mut Vec::new());
+                prop_assert!(a.bit_matches(&s1, &s2).transpositions == exp_trans);
+            }
+        }
+
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = jaro(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+        }
+    }
 }
@@ -6,6 +6,18 @@ use crate::{Algorithm, Result};
 ///
 /// The metric is always normalized on the interval from 0.0 to 1.0.
 ///
+/// By default, the prefix boost is applied to any Jaro similarity. Winkler's original
+/// comparator (and Python's textdistance and jellyfish) boosts only similarities above 0.7:
+///
+///     use textdistance::{Algorithm, JaroWinkler};
+///     let a = JaroWinkler {
+///         boost_threshold: 0.7,
+///         ..Default::default()
+///     };
+///     let jaro = textdistance::str::jaro("Friedrich", "Frank");
+///     assert!(jaro < 0.7);
+///     assert!(a.for_str("Friedrich", "Frank").nval() == jaro);
+///
 /// [Jaro-Winkler similarity]: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
 pub struct JaroWinkler {
     /// The Jaro instance to use to calculate the classic Jaro similarity.
@@ -18,6 +30,19 @@ pub struct JaroWinkler {
 
     /// `ℓ` is the maximum length of the common prefix. The default is 4.
     pub max_prefix: usize,
+
+    /// The Jaro similarity must be above this value for the prefix boost
+    /// (and the long strings adjustment) to be applied. Winkler used 0.7. Default: 0.
+    pub boost_threshold: f64,
+
+    /// If true, increase the similarity of sequences longer than 4 elements
+    /// if more than half of the elements after the common prefix agree,
+    /// like `long_tolerance` in Python's jellyfish. Default: false.
+    pub long_tolerance: bool,
+
+    /// If true, boost the common suffix instead of the common prefix.
+    /// Useful for languages where word endings matter more than beginnings. Default: false.
+    pub suffix: bool,
 }
 
 impl Default for JaroWinkler {
@@ -26,29 +51,47 @@ impl Default for JaroWinkler {
             jaro: Jaro::default(),
             prefix_weight: 0.1,
             max_prefix: 4,
+            boost_threshold: 0.,
+            long_tolerance: false,
+            suffix: false,
         }
     }
 }
 
 impl JaroWinkler {
-    fn winklerize<C, E>(&self, jaro: f64, s1: C, s2: C) -> f64
+    /// The length of the common prefix, up to `max_prefix`.
+    fn prefix_len<C, E>(&self, s1: C, s2: C) -> usize
     where
         C: Iterator<Item = E>,
-        E: Eq + core::hash::Hash,
+        E: Eq,
     {
+        s1.zip(s2)
+            .take(self.max_prefix)
+            .take_while(|(e1, e2)| e1 == e2)
+            .count()
+    }
+
+    fn winklerize<E: Eq>(&self, jaro: f64, s1: &[E], s2: &[E]) -> f64 {
         debug_assert!(self.prefix_weight * self.max_prefix as f64 <= 1.0);
-        let mut prefix_len = 0;
-        for (e1, e2) in s1.zip(s2) {
-            if e1 == e2 {
-                prefix_len += 1;
-                if prefix_len == self.max_prefix {
-                    break;
-                }
-            } else {
-                break;
+        if jaro <= self.boost_threshold {
+            return jaro;
+        }
+        let prefix_len = if self.suffix {
+            self.prefix_len(s1.iter().rev(), s2.iter().rev())
+        } else {
+            self.prefix_len(s1.iter(), s2.iter())
+        };
+        let mut sim = jaro + (self.prefix_weight * prefix_len as f64 * (1.0 - jaro));
+
+        if self.long_tolerance && s1.len().min(s2.len()) > 4 {
+            let common = self.jaro.matches(s1, s2).count;
+            if common > prefix_len + 1 && 2 * common >= s1.len().min(s2.len()) + prefix_len {
+                let rest = (common - prefix_len - 1) as f64
+                    / (s1.len() + s2.len() - prefix_len * 2 + 2) as f64;
+                sim += (1.0 - sim) * rest;
             }
         }
-        jaro + (self.prefix_weight * prefix_len as f64 * (1.0 - jaro))
+        sim
     }
 }
 
@@ -59,7 +102,7 @@ impl Algorithm<f64> for JaroWinkler {
     {
         let jaro = self.jaro.for_vec(s1, s2).nval();
         Result {
-            abs: self.winklerize(jaro, s1.iter(), s2.iter()),
+            abs: self.winklerize(jaro, s1, s2),
             is_distance: false,
             max: 1.0,
             len1: s1.len(),
@@ -71,7 +114,9 @@ impl Algorithm<f64> for JaroWinkler {
 #[cfg(test)]
 mod tests {
     use crate::str::jaro_winkler;
+    use crate::{Algorithm, JaroWinkler};
     use assert2::assert;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     fn is_close(a: f64, b: f64) -> bool {
@@ -121,4 +166,61 @@ mod tests {
         let ok = is_close(act, exp);
         assert!(ok, "jaro_winkler({}, {}) is {}, not {}", s1, s2, act, exp);
     }
+
+    #[rstest]
+    #[case("Friedrich Nietzsche", "Fran-Paul Sartre", 0.452_485)]
+    #[case("dixon", "dicksonx", 0.813_333)]
+    #[case("a", "b", 0.)]
+    fn boost_threshold(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = JaroWinkler {
+            boost_threshold: 0.7,
+            ..Default::default()
+        };
+        let act = a.for_str(s1, s2).nval();
+        assert!(is_close(act, exp), "{} != {}", act, exp);
+    }
+
+    #[rstest]
+    #[case("dwayne", "duane", 0.869_091)]
+    #[case("dixon", "dicksonx", 0.830_303)]
+    #[case("martha", "marhta", 0.970_833)]
+    #[case("Thorkel", "Thorgier", 0.867_857)]
+    #[case("abcd", "abce", 0.883_333)]
+    fn long_tolerance(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = JaroWinkler {
+            long_tolerance: true,
+            ..Default::default()
+        };
+        let act = a.for_str(s1, s2).nval();
+        assert!(is_close(act, exp), "{} != {}", act, exp);
+    }
+
+    #[rstest]
+    #[case("walking", "talking", 0.942_857)]
+    #[case("talking", "walking", 0.942_857)]
+    #[case("martha", "marhta", 0.95)]
+    #[case("abc", "abc", 1.)]
+    fn suffix(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = JaroWinkler {
+            suffix: true,
+            ..Default::default()
+        };
+        let act = a.for_str(s1, s2).nval();
+        assert!(is_close(act, exp), "{} != {}", act, exp);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*", suffix: bool, long_tolerance: bool, boost_threshold in 0. ..1.) {
+            let a = JaroWinkler {
+                boost_threshold,
+                long_tolerance,
+                suffix,
+                ..Default::default()
+            };
+            let res = a.for_str(&s1, &s2).nval();
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(res >= crate::str::jaro(&s1, &s2));
+        }
+    }
 }
@@ -0,0 +1,114 @@
+//! Jensen-Shannon divergence
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Jensen-Shannon divergence] between distributions of elements in two sequences.
+///
+/// It is a symmetric and bounded variation of [`KullbackLeibler`] divergence
+/// measuring how each distribution differs from their average.
+/// With logarithm base 2, the divergence is on the interval from 0.0 to 1.0.
+///
+///     use textdistance::{Algorithm, JensenShannon};
+///     let a = JensenShannon::default();
+///     assert!(a.for_words("the cat sat", "the cat sat").nval() == 0.);
+///     assert!(a.for_words("cat sat", "dog ran").nval() == 1.);
+///
+/// [Jensen-Shannon divergence]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+/// [`KullbackLeibler`]: crate::KullbackLeibler
+#[derive(Default)]
+pub struct JensenShannon {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for JensenShannon {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 0.,
+            (_, 0) | (0, _) => 1.,
+            (_, _) => {
+                let mut res: f64 = 0.;
+                for (e1, e2) in c1.pairs(&c2) {
+                    let p = e1 as f64 / n1 as f64;
+                    let q = e2 as f64 / n2 as f64;
+                    let m = f64::midpoint(p, q);
+                    if p > 0. {
+                        res += p / 2. * (p / m).log2();
+                    }
+                    if q > 0. {
+                        res += q / 2. * (q / m).log2();
+                    }
+                }
+                res.clamp(0., 1.)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: true,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::jensen_shannon;
+    use crate::{Algorithm, JensenShannon};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("", "abc", 1.)]
+    #[case("test", "test", 0.)]
+    #[case("abc", "cba", 0.)]
+    #[case("aaa", "bbb", 1.)]
+    #[case("test", "text", 0.25)]
+    #[case("aab", "abb", 0.081_704)]
+    #[case("nelson", "neilsen", 0.168_840)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = jensen_shannon(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "jensen_shannon({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[test]
+    fn words() {
+        let a = JensenShannon::default();
+        let act = a.for_words("to be or not to be", "to be or to be").nval();
+        assert!(is_close(act, 0.088_806));
+    }
+
+    #[test]
+    fn as_set() {
+        let a = JensenShannon { as_set: true };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 0.));
+        assert!(JensenShannon::default().for_str("aaab", "ab").nval() > 0.);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = jensen_shannon(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, jensen_shannon(&s2, &s1)));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Kulczynski similarity
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Kulczynski similarity] (the second Kulczynski coefficient) is the arithmetic mean
+/// of the fractions of each set that is shared with the other set.
+///
+/// [Kulczynski similarity]: https://doi.org/10.1002/9781118445112.stat07933
+#[derive(Default)]
+pub struct Kulczynski {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Kulczynski {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let ic = c1.intersect_count(&c2) as f64;
+                f64::midpoint(ic / n1 as f64, ic / n2 as f64)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::kulczynski;
+    use crate::{Algorithm, Kulczynski};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "abc", 0.)]
+    #[case("abc", "abc", 1.)]
+    #[case("test", "text", 0.75)]
+    #[case("nelson", "neilsen", 0.773_810)]
+    #[case("aaab", "ab", 0.75)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = kulczynski(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "kulczynski({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("test", "text", 2. / 3.)]
+    #[case("nelson", "neilsen", 0.8)]
+    #[case("aaab", "ab", 1.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = Kulczynski { as_set: true };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "kulczynski({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+}
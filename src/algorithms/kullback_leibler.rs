@@ -0,0 +1,169 @@
+//! Kullback-Leibler divergence
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Kullback-Leibler divergence] of the distribution of elements in the second sequence
+/// from the distribution of elements in the first sequence.
+///
+/// The divergence is infinite if an element occurs only in the first sequence,
+/// so both distributions are [smoothed] by adding `smoothing` to the count
+/// of each element occurring in either sequence. The divergence is not symmetric.
+///
+/// The divergence (with natural logarithm) is unbounded, and so it is normalized
+/// as `1 - exp(-divergence)`. If one of the sequences is empty, the distance is 1.0.
+///
+///     use textdistance::{Algorithm, KullbackLeibler};
+///     let a = KullbackLeibler::default();
+///     assert!(a.for_words("the cat sat", "the cat sat").nval() == 0.);
+///     assert!(a.for_words("the cat sat", "the cat ran").nval() > 0.);
+///
+/// [Kullback-Leibler divergence]: https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence
+/// [smoothed]: https://en.wikipedia.org/wiki/Additive_smoothing
+pub struct KullbackLeibler {
+    /// The pseudo-count added to the count of each element. Default: 0.5.
+    ///
+    /// It must be positive and finite, all methods panic otherwise.
+    pub smoothing: f64,
+
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Default for KullbackLeibler {
+    fn default() -> Self {
+        Self {
+            smoothing: 0.5,
+            as_set: false,
+        }
+    }
+}
+
+impl Algorithm<f64> for KullbackLeibler {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        assert!(
+            self.smoothing > 0. && self.smoothing.is_finite(),
+            "Kullback-Leibler smoothing must be positive and finite, not {}",
+            self.smoothing
+        );
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 0.,
+            (_, 0) | (0, _) => 1.,
+            (_, _) => {
+                let size = c1.pairs(&c2).count() as f64;
+                let total1 = n1 as f64 + self.smoothing * size;
+                let total2 = n2 as f64 + self.smoothing * size;
+                let mut div: f64 = 0.;
+                for (e1, e2) in c1.pairs(&c2) {
+                    let p = (e1 as f64 + self.smoothing) / total1;
+                    let q = (e2 as f64 + self.smoothing) / total2;
+                    div += p * (p / q).ln();
+                }
+                1. - (-div.max(0.)).exp()
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: true,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::kullback_leibler;
+    use crate::{Algorithm, KullbackLeibler};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("", "abc", 1.)]
+    #[case("abc", "", 1.)]
+    #[case("test", "test", 0.)]
+    #[case("abc", "cba", 0.)]
+    #[case("aaa", "bbb", 0.767_632)]
+    #[case("test", "text", 0.167_317)]
+    #[case("nelson", "neilsen", 0.132_631)]
+    #[case("neilsen", "nelson", 0.123_840)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = kullback_leibler(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(
+            ok,
+            "kullback_leibler({}, {}) is {}, not {}",
+            s1, s2, act, exp
+        );
+    }
+
+    #[rstest]
+    #[case(1., 0.564_725)]
+    #[case(0.5, 0.767_632)]
+    #[case(0.01, 0.996_550)]
+    fn smoothed(#[case] smoothing: f64, #[case] exp: f64) {
+        let a = KullbackLeibler {
+            smoothing,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("aaa", "bbb").nval(), exp));
+    }
+
+    #[rstest]
+    #[case(0.)]
+    #[case(-0.5)]
+    #[case(f64::INFINITY)]
+    #[case(f64::NAN)]
+    #[should_panic(expected = "Kullback-Leibler smoothing must be positive and finite")]
+    fn invalid_smoothing(#[case] smoothing: f64) {
+        let a = KullbackLeibler {
+            smoothing,
+            ..Default::default()
+        };
+        a.for_str("aaa", "bbb");
+    }
+
+    #[test]
+    fn tiny_smoothing() {
+        let a = KullbackLeibler {
+            smoothing: f64::MIN_POSITIVE,
+            ..Default::default()
+        };
+        assert!(a.for_str("aaa", "bbb").nval() <= 1.);
+        assert!(is_close(a.for_str("aaa", "aaa").nval(), 0.));
+    }
+
+    #[test]
+    fn as_set() {
+        let a = KullbackLeibler {
+            as_set: true,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 0.));
+        assert!(KullbackLeibler::default().for_str("aaab", "ab").nval() > 0.);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = kullback_leibler(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+        }
+    }
+}
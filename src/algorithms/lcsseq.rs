@@ -1,6 +1,7 @@
 //! Longest common subsequence
-use crate::{Algorithm, Result};
-use alloc::vec;
+use crate::diff::{from_blocks, Hunk};
+use crate::scratch::{reset, two_rows};
+use crate::{Algorithm, Result, Scratch, WithScratch};
 use alloc::vec::Vec;
 
 /// The length of the [Longest common subsequence].
@@ -8,55 +9,186 @@ use alloc::vec::Vec;
 /// It differs from the [`LCSStr`](crate::LCSStr). Unlike substrings, subsequences are not required
 /// to occupy consecutive positions within the original sequences.
 ///
+/// The subsequence itself can be found with [`LCSSeq::matches`] and [`LCSSeq::subsequence`],
+/// and [`LCSSeq::diff`] shows how to turn one sequence into the other.
+///
 /// [Longest common subsequence]: https://en.wikipedia.org/wiki/Longest_common_subsequence
 #[derive(Default)]
 pub struct LCSSeq {}
 
-impl Algorithm<usize> for LCSSeq {
-    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+#[allow(clippy::unused_self)]
+impl LCSSeq {
+    /// The length of the subsequence.
+    fn lcsseq<E: Eq>(s1: &[E], s2: &[E], buf: &mut Vec<usize>) -> Result<usize> {
         let l1 = s1.len();
         let l2 = s2.len();
-        let mut lengths = vec![vec![0; l2 + 1]; l1 + 1];
-
-        for (i, char1) in s1.iter().enumerate() {
-            for (j, char2) in s2.iter().enumerate() {
-                lengths[i + 1][j + 1] = if char1 == char2 {
-                    lengths[i][j] + 1
-                } else {
-                    lengths[i + 1][j].max(lengths[i][j + 1])
-                };
-            }
-        }
-
-        let mut result = Vec::<&E>::new();
-        let mut i = l1;
-        let mut j = l2;
-        while i != 0 && j != 0 {
-            if lengths[i][j] == lengths[i - 1][j] {
-                i -= 1;
-            } else if lengths[i][j] == lengths[i][j - 1] {
-                j -= 1;
-            } else {
-                assert!(s1[i - 1] == s2[j - 1]);
-                result.push(&s1[i - 1]);
-                i -= 1;
-                j -= 1;
-            }
-        }
-        // val: Some(result.into_iter().rev().collect::<String>())
+        let last = last_row(s1.iter(), &s2.iter(), buf);
         Result {
-            abs: result.len(),
+            abs: last[l2],
             is_distance: false,
             max: l1.max(l2),
             len1: l1,
             len2: l2,
         }
     }
+
+    /// Find the longest common subsequence.
+    ///
+    /// The result is the pairs of indices of the matched elements in the first
+    /// and the second sequence, in increasing order. It uses [Hirschberg's algorithm],
+    /// so the memory used is linear in the length of the sequences.
+    ///
+    ///     use textdistance::LCSSeq;
+    ///     let s1: Vec<char> = "test".chars().collect();
+    ///     let s2: Vec<char> = "text".chars().collect();
+    ///     let pairs = LCSSeq::default().matches(&s1, &s2);
+    ///     assert!(pairs == vec![(0, 0), (1, 1), (3, 3)]);
+    ///
+    /// [Hirschberg's algorithm]: https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm
+    pub fn matches<E: Eq>(&self, s1: &[E], s2: &[E]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        hirschberg(s1, s2, (0, 0), &mut Vec::new(), &mut Vec::new(), &mut pairs);
+        pairs
+    }
+
+    /// The elements of the longest common subsequence.
+    ///
+    ///     use textdistance::LCSSeq;
+    ///     let s1: Vec<char> = "thisisatest".chars().collect();
+    ///     let s2: Vec<char> = "testing123testing".chars().collect();
+    ///     let lcs: String = LCSSeq::default().subsequence(&s1, &s2).into_iter().collect();
+    ///     assert!(lcs == "tsitest");
+    ///
+    pub fn subsequence<'a, E: Eq>(&self, s1: &'a [E], s2: &[E]) -> Vec<&'a E> {
+        self.matches(s1, s2)
+            .into_iter()
+            .map(|(i, _)| &s1[i])
+            .collect()
+    }
+
+    /// The difference between the sequences.
+    ///
+    /// Elements of the longest common subsequence are [equal](crate::diff::Tag::Equal),
+    /// and all other elements are deleted, inserted, or replaced.
+    ///
+    ///     use textdistance::LCSSeq;
+    ///     use textdistance::diff::Tag;
+    ///     let s1 = ["the", "quick", "brown", "fox"];
+    ///     let s2 = ["the", "lazy", "brown", "dog"];
+    ///     let tags: Vec<Tag> = LCSSeq::default().diff(&s1, &s2).into_iter().map(|h| h.tag).collect();
+    ///     assert!(tags == vec![Tag::Equal, Tag::Replace, Tag::Equal, Tag::Replace]);
+    ///
+    pub fn diff<E: Eq>(&self, s1: &[E], s2: &[E]) -> Vec<Hunk> {
+        let pairs = self.matches(s1, s2);
+        let blocks = pairs.into_iter().map(|(i, j)| (i, j, 1));
+        from_blocks(blocks, s1.len(), s2.len())
+    }
+}
+
+/// The last row of the matrix of LCS lengths, that is, the length of the LCS of `s1`
+/// and each prefix of `s2`. Only two rows of the matrix are kept in the buffer.
+fn last_row<'a, 'b, E, I1, I2>(s1: I1, s2: &I2, buf: &'b mut Vec<usize>) -> &'b [usize]
+where
+    E: Eq + 'a,
+    I1: Iterator<Item = &'a E>,
+    I2: ExactSizeIterator<Item = &'a E> + Clone,
+{
+    let width = s2.len() + 1;
+    reset(buf, width * 2, 0);
+
+    let mut l1 = 0;
+    for (i, char1) in s1.enumerate() {
+        let (prev, row) = two_rows(buf, width, i);
+        for (j, char2) in s2.clone().enumerate() {
+            row[j + 1] = if char1 == char2 {
+                prev[j] + 1
+            } else {
+                row[j].max(prev[j + 1])
+            };
+        }
+        l1 += 1;
+    }
+
+    let (last, _) = two_rows(buf, width, l1);
+    last
+}
+
+/// Hirschberg's algorithm.
+///
+/// The first sequence is split in half, and the second one is split at the point where
+/// the LCS of the first half with the beginning and of the second half with the end
+/// together are the longest. Then both parts are solved recursively.
+/// The pairs of matched indices (shifted by `offset`) are added to `pairs`.
+fn hirschberg<E: Eq>(
+    s1: &[E],
+    s2: &[E],
+    offset: (usize, usize),
+    fwd: &mut Vec<usize>,
+    bwd: &mut Vec<usize>,
+    pairs: &mut Vec<(usize, usize)>,
+) {
+    // the common prefix and suffix are always a part of some LCS
+    let prefix = s1.iter().zip(s2).take_while(|(e1, e2)| e1 == e2).count();
+    pairs.extend((0..prefix).map(|k| (offset.0 + k, offset.1 + k)));
+    let (s1, s2) = (&s1[prefix..], &s2[prefix..]);
+    let (o1, o2) = (offset.0 + prefix, offset.1 + prefix);
+    let suffix = s1
+        .iter()
+        .rev()
+        .zip(s2.iter().rev())
+        .take_while(|(e1, e2)| e1 == e2)
+        .count();
+    let (s1, s2) = (&s1[..s1.len() - suffix], &s2[..s2.len() - suffix]);
+    let l1 = s1.len();
+    let l2 = s2.len();
+
+    if l1 == 1 {
+        if let Some(j) = s2.iter().position(|e| e == &s1[0]) {
+            pairs.push((o1, o2 + j));
+        }
+    } else if l1 > 1 && l2 > 0 {
+        let mid = l1 / 2;
+        let head = last_row(s1[..mid].iter(), &s2.iter(), fwd);
+        let tail = last_row(s1[mid..].iter().rev(), &s2.iter().rev(), bwd);
+        let mut split = 0;
+        let mut best = 0;
+        for j in 0..=l2 {
+            let len = head[j] + tail[l2 - j];
+            if len > best {
+                best = len;
+                split = j;
+            }
+        }
+        hirschberg(&s1[..mid], &s2[..split], (o1, o2), fwd, bwd, pairs);
+        hirschberg(
+            &s1[mid..],
+            &s2[split..],
+            (o1 + mid, o2 + split),
+            fwd,
+            bwd,
+            pairs,
+        );
+    }
+    pairs.extend((0..suffix).map(|k| (o1 + l1 + k, o2 + l2 + k)));
+}
+
+impl Algorithm<usize> for LCSSeq {
+    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+        Self::lcsseq(s1, s2, &mut Vec::new())
+    }
+}
+
+impl WithScratch<usize> for LCSSeq {
+    fn for_vec_with<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
+        Self::lcsseq(s1, s2, &mut scratch.rows)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::diff::{Hunk, Tag};
     use crate::str::lcsseq;
+    use crate::LCSSeq;
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
@@ -80,7 +212,82 @@ mod tests {
         assert!(lcsseq(s1, s2) == exp);
     }
 
+    #[rstest]
+    #[case("", "", "")]
+    #[case("abcd", "", "")]
+    #[case("ab", "cd", "")]
+    #[case("abcd", "abcd", "abcd")]
+    #[case("test", "text", "tet")]
+    #[case("thisisatest", "testing123testing", "tsitest")]
+    #[case("abcd", "d", "d")]
+    #[case("abcdefghi", "acegi", "acegi")]
+    #[case("abcdgh", "aedfhr", "adh")]
+    #[case("aggtab", "gxtxayb", "gtab")]
+    #[case("你好，世界", "再见世界", "世界")]
+    fn subsequence(#[case] s1: &str, #[case] s2: &str, #[case] exp: &str) {
+        let s1: Vec<char> = s1.chars().collect();
+        let s2: Vec<char> = s2.chars().collect();
+        let act: String = LCSSeq::default()
+            .subsequence(&s1, &s2)
+            .into_iter()
+            .collect();
+        assert!(act == exp);
+    }
+
+    #[test]
+    fn diff() {
+        let s1: Vec<char> = "kitten".chars().collect();
+        let s2: Vec<char> = "sitting".chars().collect();
+        let exp = vec![
+            (Tag::Replace, 0..1, 0..1),
+            (Tag::Equal, 1..4, 1..4),
+            (Tag::Replace, 4..5, 4..5),
+            (Tag::Equal, 5..6, 5..6),
+            (Tag::Insert, 6..6, 6..7),
+        ];
+        let exp: Vec<Hunk> = exp
+            .into_iter()
+            .map(|(tag, range1, range2)| Hunk {
+                tag,
+                range1,
+                range2,
+            })
+            .collect();
+        assert!(LCSSeq::default().diff(&s1, &s2) == exp);
+    }
+
     proptest! {
+        #[test]
+        fn prop_matches(s1 in "[abc]{0,30}", s2 in "[abc]{0,30}") {
+            let s1: Vec<char> = s1.chars().collect();
+            let s2: Vec<char> = s2.chars().collect();
+            let pairs = LCSSeq::default().matches(&s1, &s2);
+            prop_assert!(pairs.len() == lcsseq(&String::from_iter(&s1), &String::from_iter(&s2)));
+            for (i, j) in &pairs {
+                prop_assert!(s1[*i] == s2[*j]);
+            }
+            for w in pairs.windows(2) {
+                prop_assert!(w[0].0 < w[1].0 && w[0].1 < w[1].1);
+            }
+        }
+
+        #[test]
+        fn prop_diff(s1 in "[abc]{0,30}", s2 in "[abc]{0,30}") {
+            let s1: Vec<char> = s1.chars().collect();
+            let s2: Vec<char> = s2.chars().collect();
+            let hunks = LCSSeq::default().diff(&s1, &s2);
+            let mut end = (0, 0);
+            for hunk in &hunks {
+                prop_assert!((hunk.range1.start, hunk.range2.start) == end);
+                end = (hunk.range1.end, hunk.range2.end);
+                if hunk.tag == Tag::Equal {
+                    prop_assert!(s1[hunk.range1.clone()] == s2[hunk.range2.clone()]);
+                }
+            }
+            prop_assert!(end == (s1.len(), s2.len()));
+        }
+
+
         #[test]
         fn prop(s1 in ".*", s2 in ".*") {
             let res = lcsseq(&s1, &s2);
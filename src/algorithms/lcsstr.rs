@@ -1,38 +1,100 @@
 //! Longest common substring
-use crate::{Algorithm, Result};
-use alloc::vec;
+use crate::scratch::{reset, two_rows};
+use crate::{Algorithm, Result, Scratch, WithScratch};
+use alloc::vec::Vec;
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// The length of the [Longest common substring].
 ///
 /// A longest common substring of two or more strings is a longest string
-/// that is a substring of all of them.
+/// that is a substring of all of them. [`LCSStr::find`] also tells where it is.
+///
+/// Short sequences are compared using a matrix, in `O(l1 * l2)` time.
+/// For long sequences, a [suffix automaton] of the first sequence is built instead,
+/// and the longest common substring is found in linear time. The automaton needs
+/// a lot of memory, so [`WithScratch`] methods always use the matrix.
+/// Use [`LCSStr::find_with_threshold`] to choose the length where the automaton kicks in.
 ///
 /// [Longest common substring]: https://en.wikipedia.org/wiki/Longest_common_substring
+/// [suffix automaton]: https://en.wikipedia.org/wiki/Suffix_automaton
 #[derive(Default)]
 pub struct LCSStr {}
 
-impl Algorithm<usize> for LCSStr {
-    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
-        let l1 = s1.len();
-        let l2 = s2.len();
-        let mut dp = vec![vec![0; l2 + 1]; l1 + 1];
-        // let mut result_end = 0;
-        let mut result_len = 0;
+impl LCSStr {
+    /// The length from which [`LCSStr::find`] and [`Algorithm`] methods
+    /// use the suffix automaton.
+    pub const AUTOMATON_THRESHOLD: usize = 128;
+
+    /// Find the longest common substring.
+    ///
+    /// The result is the start of the substring in the first sequence,
+    /// the start of it in the second sequence, and its length.
+    /// If there are several longest common substrings, the one that ends first
+    /// in the second sequence is returned, with its first occurrence in the first sequence.
+    ///
+    ///     use textdistance::LCSStr;
+    ///     let s1: Vec<char> = "GeeksforGeeks".chars().collect();
+    ///     let s2: Vec<char> = "GeeksQuiz".chars().collect();
+    ///     assert!(LCSStr::default().find(&s1, &s2) == (0, 0, 5));
+    ///     let s2: Vec<char> = "forGeeks".chars().collect();
+    ///     assert!(LCSStr::default().find(&s1, &s2) == (5, 0, 8));
+    ///
+    pub fn find<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> (usize, usize, usize) {
+        self.find_with_threshold(s1, s2, Self::AUTOMATON_THRESHOLD)
+    }
+
+    /// Find the longest common substring, see [`LCSStr::find`].
+    ///
+    /// The suffix automaton is used if both sequences are at least `automaton_threshold`
+    /// long. The automaton is available only with the `std` feature.
+    ///
+    ///     use textdistance::LCSStr;
+    ///     let s1: Vec<char> = "GeeksforGeeks".chars().collect();
+    ///     let s2: Vec<char> = "forGeeks".chars().collect();
+    ///     assert!(LCSStr {}.find_with_threshold(&s1, &s2, 0) == (5, 0, 8));
+    ///
+    pub fn find_with_threshold<E: Eq + Hash>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        automaton_threshold: usize,
+    ) -> (usize, usize, usize) {
+        #[cfg(not(feature = "std"))]
+        let _ = automaton_threshold;
+        #[cfg(feature = "std")]
+        if s1.len().min(s2.len()) >= automaton_threshold {
+            return Automaton::new(s1).find(s2);
+        }
+        Self::find_matrix(s1, s2, &mut Vec::new())
+    }
+
+    /// Find the longest common substring using the matrix of lengths of common suffixes.
+    /// Only two rows of the matrix are kept.
+    fn find_matrix<E: Eq>(s1: &[E], s2: &[E], buf: &mut Vec<usize>) -> (usize, usize, usize) {
+        let width = s2.len() + 1;
+        reset(buf, width * 2, 0);
+        let mut best = (0, 0, 0);
         for (i, c1) in s1.iter().enumerate() {
+            let (prev, row) = two_rows(buf, width, i);
             for (j, c2) in s2.iter().enumerate() {
-                if c1 == c2 {
-                    let new_len = dp[i][j] + 1;
-                    dp[i + 1][j + 1] = new_len;
-                    if new_len > result_len {
-                        result_len = new_len;
-                        // result_end = i + 1;
-                    };
+                let len = if c1 == c2 { prev[j] + 1 } else { 0 };
+                row[j + 1] = len;
+                // rows go in order of the first sequence, so for the same end
+                // in the second sequence the first occurrence is already found
+                let (_, best_j, best_len) = best;
+                if len > best_len || len == best_len && len != 0 && j + 1 - len < best_j {
+                    best = (i + 1 - len, j + 1 - len, len);
                 }
             }
         }
-        // s1[(result_end - result_len)..result_end].to_vec()
+        best
+    }
+
+    fn result(l1: usize, l2: usize, len: usize) -> Result<usize> {
         Result {
-            abs: result_len,
+            abs: len,
             is_distance: false,
             max: l1.max(l2),
             len1: l1,
@@ -41,9 +103,158 @@ impl Algorithm<usize> for LCSStr {
     }
 }
 
+/// A state of the [`Automaton`], a class of substrings that end at the same positions.
+#[cfg(feature = "std")]
+struct State {
+    /// The length of the longest substring in the class.
+    len: usize,
+
+    /// The state of the longest suffix that ends at more positions.
+    link: Option<usize>,
+
+    /// The last added transition from the state in [`Automaton::edges`].
+    last_edge: Option<usize>,
+
+    /// The position of the last element of the first occurrence of the substrings.
+    first_end: usize,
+}
+
+/// The suffix automaton: the smallest automaton that accepts all suffixes of the sequence.
+/// It has at most `2 * len` states and `3 * len` transitions and is built in linear time.
+///
+/// Transitions of all states are stored in one map, so a state takes constant memory
+/// besides its transitions.
+#[cfg(feature = "std")]
+struct Automaton<'a, E> {
+    states: Vec<State>,
+
+    /// Transitions by the state and the next element.
+    next: HashMap<(usize, &'a E), usize>,
+
+    /// The element of each transition and the previous transition from the same state,
+    /// to list the transitions of a state when it's cloned.
+    edges: Vec<(&'a E, Option<usize>)>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, E: Eq + Hash> Automaton<'a, E> {
+    fn new(s: &'a [E]) -> Self {
+        let mut automaton = Self {
+            states: Vec::with_capacity(s.len() * 2 + 1),
+            next: HashMap::with_capacity(s.len() * 3),
+            edges: Vec::with_capacity(s.len() * 3),
+        };
+        automaton.states.push(State {
+            len: 0,
+            link: None,
+            last_edge: None,
+            first_end: 0,
+        });
+        let mut last = 0;
+        for (i, e) in s.iter().enumerate() {
+            let cur = automaton.states.len();
+            automaton.states.push(State {
+                len: automaton.states[last].len + 1,
+                link: Some(0),
+                last_edge: None,
+                first_end: i,
+            });
+            let mut p = Some(last);
+            while let Some(pi) = p {
+                if automaton.next.contains_key(&(pi, e)) {
+                    break;
+                }
+                automaton.add_edge(pi, e, cur);
+                p = automaton.states[pi].link;
+            }
+            if let Some(pi) = p {
+                let q = automaton.next[&(pi, e)];
+                if automaton.states[pi].len + 1 == automaton.states[q].len {
+                    automaton.states[cur].link = Some(q);
+                } else {
+                    let clone = automaton.states.len();
+                    automaton.states.push(State {
+                        len: automaton.states[pi].len + 1,
+                        link: automaton.states[q].link,
+                        last_edge: None,
+                        first_end: automaton.states[q].first_end,
+                    });
+                    let mut edge = automaton.states[q].last_edge;
+                    while let Some(ei) = edge {
+                        let (e, prev) = automaton.edges[ei];
+                        automaton.add_edge(clone, e, automaton.next[&(q, e)]);
+                        edge = prev;
+                    }
+                    let mut p = Some(pi);
+                    while let Some(pi) = p {
+                        match automaton.next.get_mut(&(pi, e)) {
+                            Some(next) if *next == q => *next = clone,
+                            _ => break,
+                        }
+                        p = automaton.states[pi].link;
+                    }
+                    automaton.states[q].link = Some(clone);
+                    automaton.states[cur].link = Some(clone);
+                }
+            }
+            last = cur;
+        }
+        automaton
+    }
+
+    fn add_edge(&mut self, from: usize, e: &'a E, to: usize) {
+        self.next.insert((from, e), to);
+        self.edges.push((e, self.states[from].last_edge));
+        self.states[from].last_edge = Some(self.edges.len() - 1);
+    }
+
+    /// Walk the second sequence through the automaton, keeping the longest
+    /// suffix of the walked part that is a substring of the first sequence.
+    fn find(&self, s2: &[E]) -> (usize, usize, usize) {
+        let mut state = 0;
+        let mut len = 0;
+        let mut best = (0, 0, 0);
+        for (j, e) in s2.iter().enumerate() {
+            while !self.next.contains_key(&(state, e)) {
+                let Some(link) = self.states[state].link else {
+                    break;
+                };
+                state = link;
+                len = self.states[state].len;
+            }
+            if let Some(&next) = self.next.get(&(state, e)) {
+                state = next;
+                len += 1;
+            } else {
+                len = 0;
+            }
+            if len > best.2 {
+                let first_end = self.states[state].first_end;
+                best = (first_end + 1 - len, j + 1 - len, len);
+            }
+        }
+        best
+    }
+}
+
+impl Algorithm<usize> for LCSStr {
+    fn for_vec<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+        let (_, _, len) = self.find(s1, s2);
+        Self::result(s1.len(), s2.len(), len)
+    }
+}
+
+impl WithScratch<usize> for LCSStr {
+    fn for_vec_with<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
+        let (_, _, len) = Self::find_matrix(s1, s2, &mut scratch.rows);
+        Self::result(s1.len(), s2.len(), len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::str::lcsstr;
+    use crate::{Algorithm, LCSStr};
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
@@ -75,7 +286,46 @@ mod tests {
         assert!(f("корвет", "привет") == 3);
     }
 
+    #[rstest]
+    #[case("", "", (0, 0, 0))]
+    #[case("abc", "xyz", (0, 0, 0))]
+    #[case("abcdef", "bcd", (1, 0, 3))]
+    #[case("bcd", "abcdef", (0, 1, 3))]
+    #[case("abcdxyz", "xyzabcd", (0, 3, 4))]
+    #[case("abab", "baba", (1, 0, 3))]
+    #[case("xabyab", "ab", (1, 0, 2))]
+    #[case("ab", "xabyab", (0, 1, 2))]
+    fn find(#[case] s1: &str, #[case] s2: &str, #[case] exp: (usize, usize, usize)) {
+        let s1: Vec<char> = s1.chars().collect();
+        let s2: Vec<char> = s2.chars().collect();
+        for automaton_threshold in [0, usize::MAX] {
+            assert!(LCSStr {}.find_with_threshold(&s1, &s2, automaton_threshold) == exp);
+        }
+    }
+
+    #[test]
+    fn long() {
+        let s1: Vec<u32> = (0..50_000).collect();
+        let mut s2: Vec<u32> = (50_000..100_000).collect();
+        s2[20_000..21_500].copy_from_slice(&s1[3_000..4_500]);
+        let a = LCSStr::default();
+        assert!(a.find(&s1, &s2) == (3_000, 20_000, 1_500));
+        assert!(a.for_vec(&s1, &s2).val() == 1_500);
+    }
+
     proptest! {
+        #[test]
+        fn prop_automaton(s1 in "[abc]{0,40}", s2 in "[abc]{0,40}") {
+            let s1: Vec<char> = s1.chars().collect();
+            let s2: Vec<char> = s2.chars().collect();
+            let automaton = LCSStr {}.find_with_threshold(&s1, &s2, 0);
+            let matrix = LCSStr {}.find_with_threshold(&s1, &s2, usize::MAX);
+            prop_assert!(automaton == matrix);
+            let (i, j, len) = automaton;
+            prop_assert!(s1[i..i + len] == s2[j..j + len]);
+        }
+
+
         #[test]
         fn prop(s1 in ".*", s2 in ".*") {
             let res = lcsstr(&s1, &s2);
@@ -1,6 +1,9 @@
 //! Levenshtein distance
-use crate::{Algorithm, Result};
+use crate::diff::{from_blocks, Hunk};
+use crate::{Algorithm, Result, Scratch, WithScratch};
+use alloc::vec;
 use alloc::vec::Vec;
+use core::borrow::Borrow;
 
 /// [Levenshtein distance] is an edit distance between two sequences.
 ///
@@ -32,79 +35,138 @@ impl Default for Levenshtein {
     }
 }
 
-impl Algorithm<usize> for Levenshtein {
-    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<usize>
+impl Levenshtein {
+    /// The distance calculated with a single row of the matrix kept in `cache`.
+    fn levenshtein<E, B, C>(&self, s1: &[E], s2: C, cache: &mut Vec<usize>) -> Result<usize>
     where
-        C: Iterator<Item = E>,
         E: Eq,
+        B: Borrow<E>,
+        C: Iterator<Item = B>,
     {
-        let s1: Vec<E> = s1.collect();
         let l1 = s1.len();
-        if l1 == 0 {
-            let l2 = s2.count();
-            return Result {
-                abs: l2,
-                is_distance: true,
-                max: l1.max(l2),
-                len1: l1,
-                len2: l2,
-            };
-        }
-
-        let mut cache: Vec<usize> = (1..).take(l1).collect();
-        let mut dist1;
-        let mut dist2;
+        // the distance between the first i elements of s1 and the part of s2 read so far
+        cache.clear();
+        cache.extend((1..=l1).map(|i1| i1 * self.del_cost));
 
-        let mut result = 0;
+        let mut result = l1 * self.del_cost;
         let mut l2 = 0;
         for (i2, c2) in s2.enumerate() {
-            result = i2;
-            dist1 = i2;
+            let c2 = c2.borrow();
             l2 += 1;
-
+            let mut diag = i2 * self.ins_cost;
+            result = diag + self.ins_cost;
             for (i1, c1) in s1.iter().enumerate() {
-                dist2 = if c1 == &c2 {
-                    dist1
-                } else {
-                    dist1 + self.sub_cost
-                };
-                dist1 = cache[i1];
-                result = if dist1 > result {
-                    if dist2 > result {
-                        result + self.del_cost
-                    } else {
-                        dist2
-                    }
-                } else if dist2 > dist1 {
-                    dist1 + self.ins_cost
-                } else {
-                    dist2
-                };
+                let sub_cost = if c1 == c2 { 0 } else { self.sub_cost };
+                let up = cache[i1];
+                result = (diag + sub_cost) // substitution
+                    .min(result + self.del_cost) // deletion
+                    .min(up + self.ins_cost); // insertion
+                diag = up;
                 cache[i1] = result;
             }
         }
-        if l2 == 0 {
-            return Result {
-                abs: l1,
-                is_distance: true,
-                max: l1.max(l2),
-                len1: l1,
-                len2: l2,
-            };
-        }
         Result {
             abs: result,
             is_distance: true,
-            max: l1.max(l2),
+            max: self.max_distance(l1, l2),
             len1: l1,
             len2: l2,
         }
     }
+
+    /// The distance between sequences of the given lengths that have no elements in common.
+    ///
+    /// It's the maximum distance for these lengths. For the default costs,
+    /// it's the length of the longest sequence.
+    fn max_distance(&self, l1: usize, l2: usize) -> usize {
+        let common = l1.min(l2);
+        let sub_cost = self.sub_cost.min(self.del_cost + self.ins_cost);
+        common * sub_cost + (l1 - common) * self.del_cost + (l2 - common) * self.ins_cost
+    }
+
+    /// The cheapest way to turn the first sequence into the second one.
+    ///
+    /// Elements that are kept are [equal](crate::diff::Tag::Equal), and all others are
+    /// deleted, inserted, or replaced, with the same costs as for the distance.
+    /// Unlike the distance, it needs the whole matrix of `l1 * l2` elements in memory.
+    ///
+    ///     use textdistance::Levenshtein;
+    ///     use textdistance::diff::Tag;
+    ///     let s1: Vec<char> = "kitten".chars().collect();
+    ///     let s2: Vec<char> = "sitting".chars().collect();
+    ///     let hunks = Levenshtein::default().diff(&s1, &s2);
+    ///     assert!(hunks[0].tag == Tag::Replace);
+    ///     assert!(hunks[0].range1 == (0..1));
+    ///
+    pub fn diff<E: Eq>(&self, s1: &[E], s2: &[E]) -> Vec<Hunk> {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let width = l2 + 1;
+        let mut dist = vec![0; (l1 + 1) * width];
+        for (j, cell) in dist[..width].iter_mut().enumerate() {
+            *cell = j * self.ins_cost;
+        }
+        for (i, c1) in s1.iter().enumerate() {
+            let row = (i + 1) * width;
+            dist[row] = (i + 1) * self.del_cost;
+            for (j, c2) in s2.iter().enumerate() {
+                let sub_cost = if c1 == c2 { 0 } else { self.sub_cost };
+                dist[row + j + 1] = (dist[row - width + j] + sub_cost)
+                    .min(dist[row - width + j + 1] + self.del_cost)
+                    .min(dist[row + j] + self.ins_cost);
+            }
+        }
+
+        // walk back from the end, preferring substitutions to deletions to insertions
+        let mut blocks: Vec<(usize, usize, usize)> = Vec::new();
+        let (mut i, mut j) = (l1, l2);
+        while i > 0 || j > 0 {
+            let cur = dist[i * width + j];
+            if i > 0 && j > 0 {
+                let equal = s1[i - 1] == s2[j - 1];
+                let sub_cost = if equal { 0 } else { self.sub_cost };
+                if cur == dist[(i - 1) * width + j - 1] + sub_cost {
+                    if equal {
+                        blocks.push((i - 1, j - 1, 1));
+                    }
+                    i -= 1;
+                    j -= 1;
+                    continue;
+                }
+            }
+            if i > 0 && cur == dist[(i - 1) * width + j] + self.del_cost {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+        blocks.reverse();
+        from_blocks(blocks, l1, l2)
+    }
+}
+
+impl Algorithm<usize> for Levenshtein {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<usize>
+    where
+        C: Iterator<Item = E>,
+        E: Eq,
+    {
+        let s1: Vec<E> = s1.collect();
+        self.levenshtein(&s1, s2, &mut Vec::new())
+    }
+}
+
+impl WithScratch<usize> for Levenshtein {
+    fn for_vec_with<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
+        self.levenshtein(s1, s2.iter(), &mut scratch.rows)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::diff::Tag;
     use crate::str::levenshtein;
+    use crate::{Algorithm, Levenshtein};
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
@@ -145,6 +207,21 @@ mod tests {
         assert!(levenshtein(s1, s2) == exp);
     }
 
+    #[rstest]
+    #[case("", "abc", 6)]
+    #[case("abc", "", 9)]
+    #[case("abc", "abd", 4)]
+    #[case("ab", "ba", 5)]
+    #[case("abc", "xyz", 12)]
+    fn costs(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let a = Levenshtein {
+            del_cost: 3,
+            ins_cost: 2,
+            sub_cost: 4,
+        };
+        assert!(a.for_str(s1, s2).val() == exp);
+    }
+
     proptest! {
         #[test]
         fn prop(s1 in ".*", s2 in ".*") {
@@ -153,5 +230,44 @@ mod tests {
             prop_assert_eq!(res, res2);
             prop_assert!(res <= s1.len() || res <= s2.len());
         }
+
+        #[test]
+        fn prop_diff(
+            s1 in "[abc]{0,20}",
+            s2 in "[abc]{0,20}",
+            costs in proptest::array::uniform3(1..5usize),
+        ) {
+            let [del_cost, ins_cost, sub_cost] = costs;
+            let c1: Vec<char> = s1.chars().collect();
+            let c2: Vec<char> = s2.chars().collect();
+            for a in [Levenshtein::default(), Levenshtein { del_cost, ins_cost, sub_cost }] {
+                let hunks = a.diff(&c1, &c2);
+                // hunks other than equal have no elements in common on the cheapest path
+                let cost: usize = hunks
+                    .iter()
+                    .filter(|h| h.tag != Tag::Equal)
+                    .map(|h| a.max_distance(h.range1.len(), h.range2.len()))
+                    .sum();
+                prop_assert!(cost == a.for_vec(&c1, &c2).val());
+                prop_assert!(cost == a.for_str(&s1, &s2).val());
+                for h in hunks.iter().filter(|h| h.tag == Tag::Equal) {
+                    prop_assert!(c1[h.range1.clone()] == c2[h.range2.clone()]);
+                }
+            }
+        }
+
+        #[test]
+        fn prop_costs(
+            s1 in "[abc]{0,10}",
+            s2 in "[abc]{0,10}",
+            costs in proptest::array::uniform3(0..5usize),
+        ) {
+            let [del_cost, ins_cost, sub_cost] = costs;
+            let a = Levenshtein { del_cost, ins_cost, sub_cost };
+            let res = a.for_str(&s1, &s2);
+            prop_assert!(res.val() <= res.max);
+            let swapped = Levenshtein { del_cost: ins_cost, ins_cost: del_cost, sub_cost };
+            prop_assert!(res.val() == swapped.for_str(&s2, &s1).val());
+        }
     }
 }
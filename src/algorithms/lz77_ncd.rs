@@ -0,0 +1,149 @@
+//! LZ77 Normalized Compression Distance
+#![cfg(feature = "std")]
+use crate::compression::{Compressor, NCD};
+
+/// [Normalized Compression Distance] based on [LZ77] compression.
+///
+/// LZ77 replaces repeated substrings by references to their earlier occurrences,
+/// so the order of elements matters.
+///
+///     use textdistance::{Algorithm, LZ77NCD};
+///     let a = LZ77NCD::default();
+///     assert!(a.for_str("abcdef", "abcdef").nval() < a.for_str("abcdef", "fedcba").nval());
+///
+/// [Normalized Compression Distance]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [LZ77]: https://en.wikipedia.org/wiki/LZ77_and_LZ78
+pub type LZ77NCD = NCD<LZ77>;
+
+/// [LZ77] compressor.
+///
+/// The data is greedily split into literal bytes and back-references
+/// to the longest earlier match within the window.
+/// A literal costs 9 bits (a flag and a byte), and a back-reference
+/// costs a flag plus the bits needed to store the offset and the length.
+///
+/// [LZ77]: https://en.wikipedia.org/wiki/LZ77_and_LZ78
+pub struct LZ77 {
+    /// How far back (in bytes) to look for a match. Default: 4096.
+    pub window: usize,
+
+    /// The shortest match to encode as a back-reference.
+    /// Shorter matches are encoded as literals. Default: 3.
+    pub min_match: usize,
+
+    /// The longest match to encode as a single back-reference. Default: 18.
+    pub max_match: usize,
+}
+
+impl Default for LZ77 {
+    fn default() -> Self {
+        Self {
+            window: 4096,
+            min_match: 3,
+            max_match: 18,
+        }
+    }
+}
+
+/// How many bits it takes to store a number from 0 to n-1.
+fn bits(n: usize) -> usize {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
+}
+
+impl LZ77 {
+    /// The offset and the length of the longest match for the data at the given position.
+    fn longest_match(&self, data: &[u8], pos: usize) -> (usize, usize) {
+        let max_len = self.max_match.min(data.len() - pos);
+        let mut best = (0, 0);
+        for start in pos.saturating_sub(self.window)..pos {
+            // the match may overlap with the current position
+            let len = (0..max_len)
+                .take_while(|i| data[start + i] == data[pos + i])
+                .count();
+            if len > best.1 {
+                best = (pos - start, len);
+                if len == max_len {
+                    break;
+                }
+            }
+        }
+        best
+    }
+}
+
+impl Compressor for LZ77 {
+    fn compressed_size(&self, data: &[u8]) -> f64 {
+        let min_match = self.min_match.max(1);
+        let ref_size = 1 + bits(self.window) + bits(self.max_match.saturating_sub(min_match) + 1);
+        let mut size = 0;
+        let mut pos = 0;
+        while pos < data.len() {
+            let (_, len) = self.longest_match(data, pos);
+            if len >= min_match {
+                size += ref_size;
+                pos += len;
+            } else {
+                size += 9;
+                pos += 1;
+            }
+        }
+        size as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use crate::str::lz77_ncd;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("a", "", 1.)]
+    #[case("test", "test", 0.472_222)]
+    #[case("test", "nani", 1.)]
+    #[case("abcdef", "abcdef", 0.314_815)]
+    #[case("abcdef", "fedcba", 1.)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = lz77_ncd(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "lz77_ncd({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case(b"", 0)]
+    #[case(b"a", 9)]
+    #[case(b"abc", 27)]
+    #[case(b"abcabc", 27 + 17)]
+    #[case(b"aaaa", 9 + 17)]
+    #[case(&[b'a'; 20], 9 + 17 + 9)]
+    fn compressed_size(#[case] s: &[u8], #[case] exp: usize) {
+        assert!(LZ77::default().compressed_size(s) == exp as f64);
+    }
+
+    #[test]
+    fn window() {
+        let c = LZ77 {
+            window: 2,
+            ..Default::default()
+        };
+        assert!(c.compressed_size(b"abcabc") == 54.);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = lz77_ncd(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, lz77_ncd(&s2, &s1)));
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Manhattan distance
+#![cfg(feature = "std")]
+use crate::{Algorithm, Minkowski, Result};
+
+/// [Manhattan distance] (aka taxicab distance or L1 distance) is the sum of absolute differences of element counts.
+///
+/// It is [`Minkowski`] distance with p=1,
+/// see its documentation for how the distance is normalized.
+///
+/// [Manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+#[derive(Default)]
+pub struct Manhattan {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Manhattan {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let a = Minkowski {
+            p: 1.,
+            as_set: self.as_set,
+        };
+        a.for_iter(s1, s2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::manhattan;
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("nelson", "", 1.)]
+    #[case("abc", "cba", 0.)]
+    #[case("abc", "def", 1.)]
+    #[case("test", "text", 0.25)]
+    #[case("aab", "abb", 1. / 3.)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = manhattan(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "manhattan({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+}
@@ -0,0 +1,174 @@
+//! Minkowski distance
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Minkowski distance] between vectors of element counts.
+///
+/// The distance is normalized by `(sum(x^p) + sum(y^p))^(1/p)`, the distance
+/// between vectors of the same lengths that have no elements in common.
+/// So, the normalized distance is 0.0 for the same multisets of elements and 1.0
+/// for the sequences that have no elements in common.
+///
+/// [`Manhattan`], [`Euclidean`], and [`Chebyshev`] distances are special cases of
+/// Minkowski distance where p is 1, 2, and infinity respectively.
+///
+///     use textdistance::{Algorithm, Minkowski};
+///     let a = Minkowski {
+///         p: 3.,
+///         ..Default::default()
+///     };
+///     assert!(a.for_str("abc", "abc").nval() == 0.);
+///     assert!(a.for_str("abc", "def").nval() == 1.);
+///
+/// [Minkowski distance]: https://en.wikipedia.org/wiki/Minkowski_distance
+/// [`Manhattan`]: crate::Manhattan
+/// [`Euclidean`]: crate::Euclidean
+/// [`Chebyshev`]: crate::Chebyshev
+pub struct Minkowski {
+    /// The order of the distance, a number from 1.0 to infinity (inclusive). Default: 2.
+    ///
+    /// Smaller orders (and NaN) don't give a metric, so all methods panic on them.
+    pub p: f64,
+
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Default for Minkowski {
+    fn default() -> Self {
+        Self {
+            p: 2.,
+            as_set: false,
+        }
+    }
+}
+
+impl Minkowski {
+    fn minkowski<E: Eq + core::hash::Hash>(&self, c1: &Counter<E>, c2: &Counter<E>) -> f64 {
+        if self.p.is_infinite() {
+            let mut dist = 0;
+            let mut max = 0;
+            for (n1, n2) in c1.pairs(c2) {
+                dist = dist.max(n1.abs_diff(n2));
+                max = max.max(n1).max(n2);
+            }
+            return if max == 0 {
+                0.
+            } else {
+                dist as f64 / max as f64
+            };
+        }
+        let mut dist = 0.;
+        let mut max = 0.;
+        for (n1, n2) in c1.pairs(c2) {
+            dist += (n1.abs_diff(n2) as f64).powf(self.p);
+            max += (n1 as f64).powf(self.p) + (n2 as f64).powf(self.p);
+        }
+        if max == 0. {
+            0.
+        } else {
+            (dist / max).powf(1. / self.p)
+        }
+    }
+}
+
+impl Algorithm<f64> for Minkowski {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        assert!(
+            self.p >= 1.,
+            "Minkowski order must be at least 1, not {}",
+            self.p
+        );
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        Result {
+            abs: self.minkowski(&c1, &c2),
+            is_distance: true,
+            max: 1.,
+            len1: c1.count(),
+            len2: c2.count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Minkowski;
+    use crate::str::minkowski;
+    use crate::Algorithm;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 0.)]
+    #[case("nelson", "", 1.)]
+    #[case("", "neilsen", 1.)]
+    #[case("abc", "cba", 0.)]
+    #[case("abc", "def", 1.)]
+    #[case("test", "text", 0.408_248)]
+    #[case("aab", "abb", 0.447_214)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = minkowski(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "minkowski({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case(1., 1. / 3.)]
+    #[case(2., 0.447_214)]
+    #[case(3., 0.480_750)]
+    #[case(f64::INFINITY, 0.5)]
+    fn order(#[case] p: f64, #[case] exp: f64) {
+        let a = Minkowski {
+            p,
+            ..Default::default()
+        };
+        let act = a.for_str("aab", "abb").nval();
+        assert!(is_close(act, exp), "p={}: {} != {}", p, act, exp);
+    }
+
+    #[rstest]
+    #[case(0.5)]
+    #[case(0.)]
+    #[case(-1.)]
+    #[case(f64::NAN)]
+    #[should_panic(expected = "Minkowski order must be at least 1")]
+    fn invalid_order(#[case] p: f64) {
+        let a = Minkowski {
+            p,
+            ..Default::default()
+        };
+        a.for_str("aab", "abb");
+    }
+
+    #[test]
+    fn as_set() {
+        let a = Minkowski {
+            as_set: true,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 0.));
+        assert!(Minkowski::default().for_str("aaab", "ab").nval() > 0.);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*", p in 1. ..10.) {
+            let a = Minkowski { p, as_set: false };
+            let res = a.for_str(&s1, &s2).nval();
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, a.for_str(&s2, &s1).nval()));
+        }
+    }
+}
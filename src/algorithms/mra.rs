@@ -0,0 +1,202 @@
+//! Match Rating Approach
+use crate::phonetic::Encoder;
+use crate::{Algorithm, Result};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// [Match Rating Approach] (MRA) is a phonetic algorithm developed by Western Airlines
+/// for comparing names.
+///
+/// Both names are encoded into a codex of at most 6 letters,
+/// and the similarity rating (from 0 to 6) is the number of matching letters in the codices.
+/// If the codices' lengths differ by 3 or more, the rating is 0.
+///
+/// The names are considered the same if the rating is high enough (see [`MRA::is_match`]).
+///
+///     use textdistance::{Algorithm, MRA};
+///     let a = MRA::default();
+///     assert!(a.for_str("Catherine", "Kathryn").val() == 4); // CTHRN and KTHRYN
+///     assert!(a.is_match("Catherine", "Kathryn"));
+///     assert!(!a.is_match("Bob", "Robert"));
+///
+/// Vowels are known only for `for_str`. For other methods of [`Algorithm`], the codex
+/// keeps all elements except repeated ones.
+///
+/// [Match Rating Approach]: https://en.wikipedia.org/wiki/Match_rating_approach
+#[derive(Default)]
+pub struct MRA {}
+
+impl MRA {
+    /// The minimum rating for the codices of the given lengths to be considered a match.
+    pub fn min_rating(len1: usize, len2: usize) -> usize {
+        match len1 + len2 {
+            0..=4 => 5,
+            5..=7 => 4,
+            8..=11 => 3,
+            _ => 2,
+        }
+    }
+
+    /// Check if two names are the same according to MRA.
+    ///
+    /// Returns false if the lengths of codices differ by 3 or more.
+    pub fn is_match(&self, s1: &str, s2: &str) -> bool {
+        let c1 = str_codex(s1);
+        let c2 = str_codex(s2);
+        c1.len().abs_diff(c2.len()) < 3 && rating(&c1, &c2) >= Self::min_rating(c1.len(), c2.len())
+    }
+}
+
+/// Encode the sequence: drop all vowels except the first element, drop repeated elements,
+/// and keep only the first 3 and the last 3 elements.
+fn codex<E, I, F>(s: I, is_vowel: F) -> Vec<E>
+where
+    E: Eq + Copy,
+    I: Iterator<Item = E>,
+    F: Fn(E) -> bool,
+{
+    let mut result = Vec::new();
+    let mut prev = None;
+    for (i, e) in s.enumerate() {
+        if i == 0 || (!is_vowel(e) && prev != Some(e)) {
+            result.push(e);
+        }
+        prev = Some(e);
+    }
+    if result.len() > 6 {
+        result.drain(3..result.len() - 3);
+    }
+    result
+}
+
+fn str_codex(s: &str) -> Vec<char> {
+    let chars = s.chars().map(|c| c.to_ascii_uppercase());
+    codex(chars, |c| {
+        matches!(c, 'A' | 'E' | 'I' | 'O' | 'U') || c.is_whitespace()
+    })
+}
+
+/// The number of letters (out of 6) that match in both codices.
+fn rating<E: Eq>(c1: &[E], c2: &[E]) -> usize {
+    if c1.len().abs_diff(c2.len()) >= 3 {
+        return 0;
+    }
+
+    // drop elements matching left-to-right
+    let mut rest1 = Vec::new();
+    let mut rest2 = Vec::new();
+    for i in 0..c1.len().max(c2.len()) {
+        let e1 = c1.get(i);
+        let e2 = c2.get(i);
+        if e1 != e2 {
+            rest1.extend(e1);
+            rest2.extend(e2);
+        }
+    }
+
+    // count elements not matching right-to-left
+    let mut unmatched1 = 0;
+    let mut unmatched2 = 0;
+    for i in 0..rest1.len().max(rest2.len()) {
+        let e1 = rest1.len().checked_sub(i + 1).map(|i| rest1[i]);
+        let e2 = rest2.len().checked_sub(i + 1).map(|i| rest2[i]);
+        if e1 != e2 {
+            unmatched1 += usize::from(e1.is_some());
+            unmatched2 += usize::from(e2.is_some());
+        }
+    }
+    6usize.saturating_sub(unmatched1.max(unmatched2))
+}
+
+impl Algorithm<usize> for MRA {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<usize>
+    where
+        E: Eq + Hash,
+    {
+        let c1 = codex(s1.iter(), |_| false);
+        let c2 = codex(s2.iter(), |_| false);
+        Result {
+            abs: rating(&c1, &c2),
+            is_distance: false,
+            max: 6,
+            len1: s1.len(),
+            len2: s2.len(),
+        }
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<usize> {
+        Result {
+            abs: rating(&str_codex(s1), &str_codex(s2)),
+            is_distance: false,
+            max: 6,
+            len1: s1.chars().count(),
+            len2: s2.chars().count(),
+        }
+    }
+}
+
+impl Encoder for MRA {
+    fn encode(&self, s: &str) -> String {
+        str_codex(s).into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::str::mra;
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "", 6)]
+    #[case("Byrne", "Boern", 5)]
+    #[case("Smith", "Smyth", 5)]
+    #[case("Catherine", "Kathryn", 4)]
+    #[case("Michael", "Michelle", 6)]
+    #[case("Tim", "Timothy", 0)]
+    #[case("Franciszek", "Frances", 3)]
+    #[case("Bob", "Robert", 3)]
+    #[case("Jon", "John", 5)]
+    #[case("Williams", "Wilson", 4)]
+    #[case("Robert", "Rupert", 5)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let act = mra(s1, s2);
+        assert!(act == exp, "mra({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("Byrne", "Boern", true)]
+    #[case("Smith", "Smyth", true)]
+    #[case("Catherine", "Kathryn", true)]
+    #[case("Franciszek", "Frances", true)]
+    #[case("Tim", "Timothy", false)]
+    #[case("Bob", "Robert", false)]
+    #[case("spam", "qwer", false)]
+    fn is_match(#[case] s1: &str, #[case] s2: &str, #[case] exp: bool) {
+        assert!(MRA::default().is_match(s1, s2) == exp);
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("Byrne", "BYRN")]
+    #[case("Boern", "BRN")]
+    #[case("Smyth", "SMYTH")]
+    #[case("Catherine", "CTHRN")]
+    #[case("Bob", "BB")]
+    #[case("Franciszek", "FRNSZK")]
+    #[case("Christopher", "CHRPHR")]
+    fn encode(#[case] s: &str, #[case] exp: &str) {
+        assert!(MRA::default().encode(s) == exp);
+    }
+
+    #[test]
+    fn generic() {
+        let a = MRA::default();
+        assert!(a.for_vec(&[1, 2, 3], &[1, 2, 3]).val() == 6);
+        assert!(a.for_vec(&[1, 2, 3], &[1, 2, 4]).val() == 5);
+        assert!(a.for_vec(&[1, 1, 2], &[1, 2]).val() == 6);
+        assert!(a.for_vec(&[1], &[1, 2, 3, 4]).val() == 0);
+    }
+}
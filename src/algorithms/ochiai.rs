@@ -0,0 +1,88 @@
+//! Ochiai coefficient
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Ochiai coefficient] is the size of the intersection divided by the geometric mean
+/// of the sizes of the two sets.
+///
+/// For multisets (the default mode), it is the same as [`Cosine`] similarity.
+///
+/// [Ochiai coefficient]: https://en.wikipedia.org/wiki/Cosine_similarity#Otsuka%E2%80%93Ochiai_coefficient
+/// [`Cosine`]: crate::Cosine
+#[derive(Default)]
+pub struct Ochiai {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Algorithm<f64> for Ochiai {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let n1 = c1.count();
+        let n2 = c2.count();
+        let res = match (n1, n2) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let ic = c1.intersect_count(&c2);
+                ic as f64 / ((n1 * n2) as f64).sqrt()
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1: n1,
+            len2: n2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::ochiai;
+    use crate::{Algorithm, Ochiai};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "abc", 0.)]
+    #[case("abc", "abc", 1.)]
+    #[case("test", "text", 0.75)]
+    #[case("nelson", "neilsen", 0.771_517)]
+    #[case("aaab", "ab", core::f64::consts::FRAC_1_SQRT_2)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = ochiai(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "ochiai({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("test", "text", 2. / 3.)]
+    #[case("nelson", "neilsen", 0.8)]
+    #[case("aaab", "ab", 1.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = Ochiai { as_set: true };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "ochiai({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+}
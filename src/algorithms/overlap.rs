@@ -7,7 +7,11 @@ use crate::{Algorithm, Result};
 ///
 /// [Overlap similarity]: https://en.wikipedia.org/wiki/Overlap_coefficient
 #[derive(Default)]
-pub struct Overlap {}
+pub struct Overlap {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
 
 impl Algorithm<f64> for Overlap {
     fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
@@ -15,8 +19,8 @@ impl Algorithm<f64> for Overlap {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let n1 = c1.count();
         let n2 = c2.count();
         let res = match (n1, n2) {
@@ -39,7 +43,7 @@ impl Algorithm<f64> for Overlap {
 
 #[cfg(test)]
 mod tests {
-    use crate::str::overlap;
+    use crate::str::{overlap, overlap_set};
     use assert2::assert;
     use rstest::rstest;
 
@@ -60,4 +64,17 @@ mod tests {
         let ok = is_close(act, exp);
         assert!(ok, "overlap({}, {}) is {}, not {}", s1, s2, act, exp);
     }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("aaab", "ab", 1.)]
+    // parity with textdistance (as_set=True)
+    #[case("test", "text", 2. / 3.)]
+    #[case("nelson", "neilsen", 0.8)]
+    fn function_str_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = overlap_set(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "overlap_set({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
 }
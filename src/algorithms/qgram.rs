@@ -0,0 +1,152 @@
+//! Q-gram distance
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// [Q-gram distance] by Esko Ukkonen is the number of q-grams (substrings of length q)
+/// that occur in one sequence more times than in the other.
+///
+/// One edit operation changes at most q q-grams in each sequence, so the distance divided
+/// by `2 * q` (rounded up) is a lower bound for [`Levenshtein`] distance.
+/// It makes q-gram distance a cheap filter before calculating the edit distance.
+///
+///     use textdistance::{Algorithm, QGram};
+///     let q = QGram::default();
+///     let dist = q.for_str("kitten", "sitting").val();
+///     assert!(dist == 11);
+///     let min_lev = dist.div_ceil(2 * q.q);
+///     assert!(min_lev == 3);
+///     assert!(textdistance::str::levenshtein("kitten", "sitting") >= min_lev);
+///
+/// [Q-gram distance]: https://doi.org/10.1016/0304-3975(92)90143-4
+/// [`Levenshtein`]: crate::Levenshtein
+pub struct QGram {
+    /// The length of q-grams. Zero is treated as 1. Default: 2.
+    pub q: usize,
+
+    /// If true, non-empty sequences are padded on both sides with `q - 1` special elements,
+    /// so that the first and the last elements appear in as many q-grams as others.
+    /// Default: true.
+    pub padding: bool,
+
+    /// If true, count each unique q-gram only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
+
+impl Default for QGram {
+    fn default() -> Self {
+        Self {
+            q: 2,
+            padding: true,
+            as_set: false,
+        }
+    }
+}
+
+impl QGram {
+    /// Count q-grams in the sequence. Padding elements are represented as `None`.
+    fn qgrams<'a, E: Eq + Hash>(&self, s: &'a [E]) -> Counter<Vec<Option<&'a E>>> {
+        let q = self.q.max(1);
+        let pad = if self.padding && !s.is_empty() {
+            q - 1
+        } else {
+            0
+        };
+        let mut padded = Vec::with_capacity(s.len() + 2 * pad);
+        padded.extend(core::iter::repeat_n(None, pad));
+        padded.extend(s.iter().map(Some));
+        padded.extend(core::iter::repeat_n(None, pad));
+        Counter::from_iter_mode(padded.windows(q).map(<[_]>::to_vec), self.as_set)
+    }
+}
+
+impl Algorithm<usize> for QGram {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<usize>
+    where
+        E: Eq + Hash,
+    {
+        let c1 = self.qgrams(s1);
+        let c2 = self.qgrams(s2);
+        Result {
+            abs: c1.diff_count(&c2) + c2.diff_count(&c1),
+            is_distance: true,
+            max: c1.count() + c2.count(),
+            len1: s1.len(),
+            len2: s2.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::qgram;
+    use crate::{Algorithm, QGram};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("", "a", 2)]
+    #[case("a", "", 2)]
+    #[case("a", "a", 0)]
+    #[case("a", "b", 4)]
+    #[case("ab", "ba", 6)]
+    #[case("abc", "abc", 0)]
+    #[case("abcd", "abdc", 6)]
+    #[case("kitten", "sitting", 11)]
+    #[case("aaaa", "aaa", 1)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let act = qgram(s1, s2);
+        assert!(act == exp, "qgram({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case(1, "abc", "cba", 0)]
+    #[case(2, "a", "b", 0)]
+    #[case(2, "ab", "ba", 2)]
+    #[case(2, "kitten", "sitting", 7)]
+    #[case(3, "kitten", "sitting", 7)]
+    #[case(3, "ab", "abc", 1)]
+    fn no_padding(#[case] q: usize, #[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let a = QGram {
+            q,
+            padding: false,
+            as_set: false,
+        };
+        assert!(a.for_str(s1, s2).val() == exp);
+    }
+
+    #[test]
+    fn q() {
+        let a = QGram {
+            q: 3,
+            ..Default::default()
+        };
+        assert!(a.for_str("abc", "abc").max == 10);
+        assert!(a.for_str("abc", "abd").val() == 6);
+        assert!(a.for_words("a b c", "a b d").val() == 6);
+    }
+
+    #[test]
+    fn as_set() {
+        let a = QGram {
+            as_set: true,
+            ..Default::default()
+        };
+        assert!(a.for_str("abab", "ab").val() == 1);
+        assert!(QGram::default().for_str("abab", "ab").val() == 2);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_levenshtein(s1 in ".*", s2 in ".*", q in 1..5usize, padding: bool) {
+            let a = QGram { q, padding, as_set: false };
+            let lev = crate::str::levenshtein(&s1, &s2);
+            prop_assert!(a.for_str(&s1, &s2).val() <= lev * 2 * q);
+        }
+    }
+}
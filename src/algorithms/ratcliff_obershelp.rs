@@ -1,7 +1,11 @@
 //! Gestalt pattern matching
+use crate::diff::{from_blocks, group, Hunk};
+use crate::scratch::{reset, two_rows};
 use crate::{Algorithm, Result};
-use alloc::vec;
 use alloc::vec::Vec;
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// [Ratcliff/Obershelp similarity] is [`LCSStr`] that recursively finds matches
 /// on both sides of the longest substring.
@@ -12,62 +16,255 @@ use alloc::vec::Vec;
 ///
 /// The normalized result is the non-normalized one divided by the sum of the input string lengths.
 ///
+/// It is the algorithm behind Python's [difflib.SequenceMatcher], and the matches it finds
+/// are available the same way: see [`RatcliffObershelp::matching_blocks`],
+/// [`RatcliffObershelp::opcodes`], and [`RatcliffObershelp::grouped_opcodes`].
+///
+///     use textdistance::RatcliffObershelp;
+///     use textdistance::diff::Tag;
+///
+///     let a = RatcliffObershelp::default();
+///     let s1: Vec<char> = "qabxcd".chars().collect();
+///     let s2: Vec<char> = "abycdf".chars().collect();
+///     let tags: Vec<Tag> = a.opcodes(&s1, &s2, |_| false).into_iter().map(|h| h.tag).collect();
+///     assert!(tags == [Tag::Delete, Tag::Equal, Tag::Replace, Tag::Equal, Tag::Insert]);
+///
 /// [Ratcliff/Obershelp similarity]: https://en.wikipedia.org/wiki/Gestalt_pattern_matching
 /// [`LCSStr`]: crate::LCSStr
+/// [difflib.SequenceMatcher]: https://docs.python.org/3/library/difflib.html#difflib.SequenceMatcher
 #[derive(Default)]
 pub struct RatcliffObershelp {}
 
-impl Algorithm<usize> for RatcliffObershelp {
-    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+/// The sequences being matched and what is known about the elements of the second one.
+struct Matcher<'a, E, F> {
+    s1: &'a [E],
+    s2: &'a [E],
+    is_junk: F,
+
+    /// Which elements of the second sequence can be a part of the longest match:
+    /// not junk and not popular.
+    eligible: Vec<bool>,
+
+    /// Two rows of the matrix of lengths of common suffixes.
+    buf: Vec<usize>,
+}
+
+impl<E: Eq, F: Fn(&E) -> bool> Matcher<'_, E, F> {
+    /// Find the longest matching block in `s1[lo1..hi1]` and `s2[lo2..hi2]`,
+    /// like `find_longest_match` in difflib.
+    ///
+    /// The longest block of eligible elements is found first. The earliest one in `s1`
+    /// wins, and of those the earliest one in `s2`. Then the block is extended
+    /// with equal elements that are not junk, and finally with equal junk elements.
+    fn longest_match(
+        &mut self,
+        lo1: usize,
+        hi1: usize,
+        lo2: usize,
+        hi2: usize,
+    ) -> (usize, usize, usize) {
+        let s1 = self.s1;
+        let s2 = self.s2;
+        let width = hi2 - lo2 + 1;
+        reset(&mut self.buf, width * 2, 0);
+        let (mut i, mut j, mut size) = (lo1, lo2, 0);
+        for (n1, c1) in s1[lo1..hi1].iter().enumerate() {
+            let (prev, row) = two_rows(&mut self.buf, width, n1);
+            for (n2, c2) in s2[lo2..hi2].iter().enumerate() {
+                let len = if self.eligible[lo2 + n2] && c1 == c2 {
+                    prev[n2] + 1
+                } else {
+                    0
+                };
+                row[n2 + 1] = len;
+                if len > size {
+                    (i, j, size) = (lo1 + n1 + 1 - len, lo2 + n2 + 1 - len, len);
+                }
+            }
+        }
+
+        for junk in [false, true] {
+            while i > lo1 && j > lo2 && (self.is_junk)(&s2[j - 1]) == junk && s1[i - 1] == s2[j - 1]
+            {
+                (i, j, size) = (i - 1, j - 1, size + 1);
+            }
+            while i + size < hi1
+                && j + size < hi2
+                && (self.is_junk)(&s2[j + size]) == junk
+                && s1[i + size] == s2[j + size]
+            {
+                size += 1;
+            }
+        }
+        (i, j, size)
+    }
+}
+
+impl RatcliffObershelp {
+    /// Find the matching blocks, like `get_matching_blocks` in Python's difflib.
+    ///
+    /// Each block is a triple `(i, j, len)` meaning that `s1[i..i + len] == s2[j..j + len]`.
+    /// The blocks are sorted, adjacent blocks are merged, and the last block
+    /// is always `(s1.len(), s2.len(), 0)`.
+    ///
+    /// Elements of the second sequence for which `is_junk` returns true
+    /// never start a match but can be a part of one if they are next to it.
+    ///
+    ///     use textdistance::RatcliffObershelp;
+    ///     let a = RatcliffObershelp::default();
+    ///     let blocks = a.matching_blocks(b"abxcd", b"abcd", |_| false);
+    ///     assert!(blocks == vec![(0, 0, 2), (3, 2, 2), (5, 4, 0)]);
+    ///
+    pub fn matching_blocks<E, F>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        is_junk: F,
+    ) -> Vec<(usize, usize, usize)>
+    where
+        E: Eq + Hash,
+        F: Fn(&E) -> bool,
+    {
+        self.matching_blocks_with(s1, s2, is_junk, false)
+    }
+
+    /// Same as [`RatcliffObershelp::matching_blocks`] but with `autojunk` like in difflib.
+    ///
+    /// If `autojunk` is true, elements that make up more than 1% of the second sequence
+    /// (if it has at least 200 elements) are treated as junk. Requires the `std` feature,
+    /// without it `autojunk` is ignored.
+    ///
+    ///     use textdistance::RatcliffObershelp;
+    ///     let a = RatcliffObershelp::default();
+    ///     let s1 = "x".repeat(10) + "abc";
+    ///     let s2 = "x".repeat(300) + "abc";
+    ///     let blocks = a.matching_blocks_with(s1.as_bytes(), s2.as_bytes(), |_| false, true);
+    ///     assert!(blocks == vec![(0, 290, 13), (13, 303, 0)]);
+    ///
+    pub fn matching_blocks_with<E, F>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        is_junk: F,
+        autojunk: bool,
+    ) -> Vec<(usize, usize, usize)>
+    where
+        E: Eq + Hash,
+        F: Fn(&E) -> bool,
+    {
+        #[cfg(not(feature = "std"))]
+        let _ = autojunk;
         let l1 = s1.len();
         let l2 = s2.len();
-        let mut stack: Vec<((usize, usize), (usize, usize))> = Vec::new();
-        stack.push(((0, l1), (0, l2)));
-        let mut result = 0;
-
-        while let Some(top) = stack.pop() {
-            let ((part1_start, part1_len), (part2_start, part2_len)) = top;
-            let s1_part = s1[part1_start..(part1_start + part1_len)].iter();
-            let s2_part: Vec<&E> = s2[part2_start..(part2_start + part2_len)].iter().collect();
-
-            let mut dp = vec![vec![0; l2 + 1]; l1 + 1];
-            let mut prefix1_end = 0;
-            let mut prefix2_end = 0;
-            let mut match_len: usize = 0;
-            for (i1, c1) in s1_part.enumerate() {
-                for (i2, c2) in s2_part.iter().enumerate() {
-                    if &c1 == c2 {
-                        let new_len: usize = dp[i1][i2] + 1;
-                        dp[i1 + 1][i2 + 1] = new_len;
-                        if new_len > match_len {
-                            debug_assert!(i1 + 1 >= new_len);
-                            debug_assert!(i2 + 1 >= new_len);
-                            match_len = new_len;
-                            prefix1_end = i1 + 1;
-                            prefix2_end = i2 + 1;
-                        };
-                    }
-                }
+        let eligible: Vec<bool> = s2.iter().map(|e| !is_junk(e)).collect();
+        #[cfg(feature = "std")]
+        let eligible = if autojunk && l2 >= 200 {
+            without_popular(s2, eligible)
+        } else {
+            eligible
+        };
+        let mut matcher = Matcher {
+            s1,
+            s2,
+            is_junk,
+            eligible,
+            buf: Vec::new(),
+        };
+
+        let mut blocks: Vec<(usize, usize, usize)> = Vec::new();
+        let mut stack: Vec<(usize, usize, usize, usize)> = Vec::new();
+        stack.push((0, l1, 0, l2));
+        while let Some((lo1, hi1, lo2, hi2)) = stack.pop() {
+            let (i, j, size) = matcher.longest_match(lo1, hi1, lo2, hi2);
+            if size == 0 {
+                continue;
+            }
+            blocks.push((i, j, size));
+            if lo1 < i && lo2 < j {
+                stack.push((lo1, i, lo2, j));
+            }
+            if i + size < hi1 && j + size < hi2 {
+                stack.push((i + size, hi1, j + size, hi2));
             }
+        }
+        blocks.sort_unstable();
 
-            if match_len != 0 {
-                let prefix1_len = prefix1_end - match_len;
-                let prefix2_len = prefix2_end - match_len;
-                if prefix1_len != 0 && prefix2_len != 0 {
-                    stack.push(((part1_start, prefix1_len), (part2_start, prefix2_len)));
-                }
-                let suffix1_len = part1_len - prefix1_end;
-                let suffix2_len = part2_len - prefix2_end;
-                if suffix1_len != 0 && suffix2_len != 0 {
-                    stack.push((
-                        (part1_start + prefix1_end, suffix1_len),
-                        (part2_start + prefix2_end, suffix2_len),
-                    ));
-                }
-                result += match_len;
+        let mut merged: Vec<(usize, usize, usize)> = Vec::with_capacity(blocks.len() + 1);
+        for (i, j, size) in blocks {
+            match merged.last_mut() {
+                Some(last) if last.0 + last.2 == i && last.1 + last.2 == j => last.2 += size,
+                _ => merged.push((i, j, size)),
             }
         }
+        merged.push((l1, l2, 0));
+        merged
+    }
 
+    /// The difference between the sequences, like `get_opcodes` in Python's difflib.
+    ///
+    /// See [`RatcliffObershelp::matching_blocks`] for `is_junk`.
+    pub fn opcodes<E, F>(&self, s1: &[E], s2: &[E], is_junk: F) -> Vec<Hunk>
+    where
+        E: Eq + Hash,
+        F: Fn(&E) -> bool,
+    {
+        from_blocks(self.matching_blocks(s1, s2, is_junk), s1.len(), s2.len())
+    }
+
+    /// Groups of changes with up to `context` equal elements around them,
+    /// like `get_grouped_opcodes` in Python's difflib. See [`group`].
+    ///
+    /// See [`RatcliffObershelp::matching_blocks`] for `is_junk`.
+    ///
+    ///     use textdistance::RatcliffObershelp;
+    ///     let a = RatcliffObershelp::default();
+    ///     let s1: Vec<u32> = (1..40).collect();
+    ///     let mut s2 = s1.clone();
+    ///     s2[5] = 0;
+    ///     s2[30] = 0;
+    ///     let groups = a.grouped_opcodes(&s1, &s2, 3, |_| false);
+    ///     assert!(groups.len() == 2);
+    ///     assert!(groups[0][0].range1 == (2..5));
+    ///
+    pub fn grouped_opcodes<E, F>(
+        &self,
+        s1: &[E],
+        s2: &[E],
+        context: usize,
+        is_junk: F,
+    ) -> Vec<Vec<Hunk>>
+    where
+        E: Eq + Hash,
+        F: Fn(&E) -> bool,
+    {
+        group(self.opcodes(s1, s2, is_junk), context)
+    }
+}
+
+/// Mark elements that make up more than 1% of the sequence as not eligible.
+#[cfg(feature = "std")]
+fn without_popular<E: Eq + Hash>(s2: &[E], mut eligible: Vec<bool>) -> Vec<bool> {
+    let mut counts: HashMap<&E, usize> = HashMap::new();
+    for (e, ok) in s2.iter().zip(&eligible) {
+        if *ok {
+            *counts.entry(e).or_insert(0) += 1;
+        }
+    }
+    let popular = s2.len() / 100 + 1;
+    for (e, ok) in s2.iter().zip(&mut eligible) {
+        if counts.get(e).is_some_and(|n| *n > popular) {
+            *ok = false;
+        }
+    }
+    eligible
+}
+
+impl Algorithm<usize> for RatcliffObershelp {
+    fn for_vec<E: Eq + Hash>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let blocks = self.matching_blocks(s1, s2, |_| false);
+        let result: usize = blocks.iter().map(|(_, _, size)| size).sum();
         Result {
             abs: 2 * result,
             is_distance: false,
@@ -83,8 +280,10 @@ mod tests {
     #![allow(clippy::float_cmp)]
 
     use super::{Algorithm, RatcliffObershelp};
+    use crate::diff::{Hunk, Tag};
     use crate::str::ratcliff_obershelp;
     use assert2::assert;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     #[rstest]
@@ -110,4 +309,79 @@ mod tests {
                 == 26
         );
     }
+
+    // parity with difflib
+    #[rstest]
+    #[case("abxcd", "abcd", false, vec![(0, 0, 2), (3, 2, 2), (5, 4, 0)])]
+    #[case(" abcd", "abcd abcd", false, vec![(0, 4, 5), (5, 9, 0)])]
+    #[case(" abcd", "abcd abcd", true, vec![(1, 0, 4), (5, 9, 0)])]
+    #[case("", "", false, vec![(0, 0, 0)])]
+    #[case("abc", "", false, vec![(3, 0, 0)])]
+    fn matching_blocks(
+        #[case] s1: &str,
+        #[case] s2: &str,
+        #[case] space_is_junk: bool,
+        #[case] exp: Vec<(usize, usize, usize)>,
+    ) {
+        let s1: Vec<char> = s1.chars().collect();
+        let s2: Vec<char> = s2.chars().collect();
+        let a = RatcliffObershelp::default();
+        let act = a.matching_blocks(&s1, &s2, |c| space_is_junk && *c == ' ');
+        assert!(act == exp);
+    }
+
+    #[test]
+    fn opcodes() {
+        let s1: Vec<char> = "qabxcd".chars().collect();
+        let s2: Vec<char> = "abycdf".chars().collect();
+        let exp = vec![
+            (Tag::Delete, 0..1, 0..0),
+            (Tag::Equal, 1..3, 0..2),
+            (Tag::Replace, 3..4, 2..3),
+            (Tag::Equal, 4..6, 3..5),
+            (Tag::Insert, 6..6, 5..6),
+        ];
+        let exp: Vec<Hunk> = exp
+            .into_iter()
+            .map(|(tag, range1, range2)| Hunk {
+                tag,
+                range1,
+                range2,
+            })
+            .collect();
+        let a = RatcliffObershelp::default();
+        assert!(a.opcodes(&s1, &s2, |_| false) == exp);
+    }
+
+    #[test]
+    fn autojunk() {
+        let s1 = "x".repeat(10) + "abc" + &"x".repeat(200);
+        let s2 = "x".repeat(300) + "abc";
+        let s1: Vec<char> = s1.chars().collect();
+        let s2: Vec<char> = s2.chars().collect();
+        let a = RatcliffObershelp::default();
+        assert!(a.matching_blocks(&s1, &s2, |_| false) == vec![(13, 0, 200), (213, 303, 0)]);
+        assert!(
+            a.matching_blocks_with(&s1, &s2, |_| false, false) == vec![(13, 0, 200), (213, 303, 0)]
+        );
+        let act = a.matching_blocks_with(&s1, &s2, |_| false, true);
+        assert!(act == vec![(0, 290, 13), (213, 303, 0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matching_blocks(s1 in "[abc ]{0,30}", s2 in "[abc ]{0,30}") {
+            let s1: Vec<char> = s1.chars().collect();
+            let s2: Vec<char> = s2.chars().collect();
+            let a = RatcliffObershelp::default();
+            let blocks = a.matching_blocks(&s1, &s2, |c| *c == ' ');
+            prop_assert!(blocks.last() == Some(&(s1.len(), s2.len(), 0)));
+            for (i, j, size) in &blocks {
+                prop_assert!(s1[*i..i + size] == s2[*j..j + size]);
+            }
+            for w in blocks.windows(2) {
+                prop_assert!(w[0].0 + w[0].2 <= w[1].0 && w[0].1 + w[0].2 <= w[1].1);
+            }
+        }
+    }
 }
@@ -9,7 +9,11 @@ use crate::{Algorithm, Result};
 ///
 /// [Roberts similarity]: https://github.com/chrislit/abydos/blob/master/abydos/distance/_roberts.py
 #[derive(Default)]
-pub struct Roberts {}
+pub struct Roberts {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
 
 impl Algorithm<f64> for Roberts {
     fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
@@ -17,8 +21,8 @@ impl Algorithm<f64> for Roberts {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let n1 = c1.count();
         let n2 = c2.count();
         if n1 == 0 && n2 == 0 {
@@ -57,6 +61,7 @@ impl Algorithm<f64> for Roberts {
 #[cfg(test)]
 mod tests {
     use crate::str::roberts;
+    use crate::{Algorithm, Roberts};
     use assert2::assert;
     use rstest::rstest;
 
@@ -88,4 +93,11 @@ mod tests {
         let ok = is_close(act, exp);
         assert!(ok, "roberts({}, {}) is {}, not {}", s1, s2, act, exp);
     }
+
+    #[test]
+    fn as_set() {
+        let a = Roberts { as_set: true };
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 1.));
+        assert!(is_close(a.for_str("aaab", "abcc").nval(), 0.8));
+    }
 }
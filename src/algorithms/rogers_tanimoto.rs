@@ -0,0 +1,127 @@
+//! Rogers-Tanimoto similarity
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Rogers-Tanimoto similarity] is a variation of [`SokalMichener`] similarity
+/// where mismatches have double weight.
+///
+/// The elements that occur in neither of the sequences can be counted only if
+/// the `universe` of all possible elements is known. See [`SokalMichener`] for details.
+///
+/// [Rogers-Tanimoto similarity]: https://doi.org/10.1126/science.132.3434.1115
+/// [`SokalMichener`]: crate::SokalMichener
+#[derive(Default)]
+pub struct RogersTanimoto {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+
+    /// The total number of unique elements that may occur in the sequences
+    /// (for example, 26 for English letters). Elements that occur in neither of the sequences
+    /// are counted as matches. Default: 0, no such elements.
+    pub universe: usize,
+}
+
+impl Algorithm<f64> for RogersTanimoto {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let uc = c1.union_count(&c2);
+        let ic = c1.intersect_count(&c2);
+        // matches: elements that are in both sequences or in neither of them.
+        // The universe is a number of unique elements, so the ones in neither
+        // are counted as unique no matter if the sequences are multisets.
+        let absent = self.universe.saturating_sub(c1.merge(&c2).keys().count());
+        let matches = ic + absent;
+        let mismatches = uc - ic;
+        let total = matches + 2 * mismatches;
+        Result {
+            abs: if total == 0 {
+                1.
+            } else {
+                matches as f64 / total as f64
+            },
+            is_distance: false,
+            max: 1.,
+            len1: c1.count(),
+            len2: c2.count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::rogers_tanimoto;
+    use crate::{Algorithm, RogersTanimoto};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "abc", 0.)]
+    #[case("abc", "abc", 1.)]
+    #[case("test", "text", 3. / 7.)]
+    #[case("nelson", "neilsen", 5. / 11.)]
+    #[case("aaab", "ab", 1. / 3.)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = rogers_tanimoto(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(
+            ok,
+            "rogers_tanimoto({}, {}) is {}, not {}",
+            s1, s2, act, exp
+        );
+    }
+
+    #[rstest]
+    #[case("test", "text", 1. / 3.)]
+    #[case("nelson", "neilsen", 0.5)]
+    #[case("aaab", "ab", 1.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = RogersTanimoto {
+            as_set: true,
+            ..Default::default()
+        };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "rogers_tanimoto({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+
+    #[test]
+    fn universe() {
+        let a = RogersTanimoto {
+            universe: 26,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("test", "text").nval(), 25. / 29.));
+        assert!(is_close(a.for_str("abc", "def").nval(), 20. / 32.));
+        // 2 matches of "a" and "b", 2 extra "a", and 24 letters in neither
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 26. / 30.));
+        assert!(is_close(a.for_str("aaaa", "aaaa").nval(), 1.));
+    }
+
+    #[test]
+    fn universe_as_set() {
+        let a = RogersTanimoto {
+            as_set: true,
+            universe: 26,
+        };
+        assert!(is_close(a.for_str("test", "text").nval(), 24. / 28.));
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 1.));
+    }
+}
@@ -1,6 +1,6 @@
 //! Smith-Waterman sequence alignment
-use crate::{Algorithm, Result};
-use alloc::vec;
+use crate::scratch::{reset, two_rows};
+use crate::{Algorithm, Result, Scratch, WithScratch};
 use alloc::vec::Vec;
 
 /// [Smith-Waterman similarity] is edit-based and designed for nucleic acid (and protein) sequences.
@@ -27,25 +27,29 @@ impl Default for SmithWaterman {
     }
 }
 
-impl Algorithm<usize> for SmithWaterman {
-    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+impl SmithWaterman {
+    /// The score in the last cell of the matrix. Only two rows of the matrix are kept.
+    fn smith_waterman<E: Eq>(&self, s1: &[E], s2: &[E], buf: &mut Vec<isize>) -> Result<usize> {
         let l1 = s1.len();
         let l2 = s2.len();
-        let mut dist_mat: Vec<Vec<isize>> = vec![vec![0; l2 + 1]; l1 + 1];
+        let width = l2 + 1;
+        reset(buf, width * 2, 0);
         for (i, sc1) in s1.iter().enumerate() {
+            let (prev, row) = two_rows(buf, width, i);
             for (j, sc2) in s2.iter().enumerate() {
                 let cost = if sc1 == sc2 {
                     self.match_cost
                 } else {
                     self.mismatch_cost
                 };
-                let match_ = dist_mat[i][j] - cost;
-                let delete = dist_mat[i][j + 1] - self.gap_cost;
-                let insert = dist_mat[i + 1][j] - self.gap_cost;
-                dist_mat[i + 1][j + 1] = 0.max(match_).max(delete).max(insert);
+                let match_ = prev[j] - cost;
+                let delete = prev[j + 1] - self.gap_cost;
+                let insert = row[j] - self.gap_cost;
+                row[j + 1] = 0.max(match_).max(delete).max(insert);
             }
         }
-        let result = dist_mat[l1][l2];
+        let (last, _) = two_rows(buf, width, l1);
+        let result = last[l2];
         Result {
             #[allow(clippy::cast_sign_loss)]
             abs: result as usize,
@@ -57,6 +61,18 @@ impl Algorithm<usize> for SmithWaterman {
     }
 }
 
+impl Algorithm<usize> for SmithWaterman {
+    fn for_vec<E: Eq>(&self, s1: &[E], s2: &[E]) -> Result<usize> {
+        self.smith_waterman(s1, s2, &mut Vec::new())
+    }
+}
+
+impl WithScratch<usize> for SmithWaterman {
+    fn for_vec_with<E: Eq>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<usize> {
+        self.smith_waterman(s1, s2, &mut scratch.irows)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::str::smith_waterman;
@@ -0,0 +1,229 @@
+//! Soft TF-IDF similarity
+#![cfg(feature = "std")]
+use super::jaro_winkler::JaroWinkler;
+use crate::algorithm::bigrams;
+use crate::corpus::{stable_hash, std_hash, Corpus};
+use crate::{Algorithm, Result};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// [Soft TF-IDF] by Cohen, Ravikumar, and Fienberg is [`TfIdf`] that also
+/// takes into account similar but not identical elements.
+///
+/// For each element of the first sequence, the most similar element of the second one
+/// is found. If their similarity is at least `threshold`, the product of their
+/// TF-IDF weights multiplied by the similarity is added to the result.
+///
+/// When comparing words (see [`Algorithm::for_words`]), the similarity of words
+/// is calculated using [`JaroWinkler`]. For other element types, only equal
+/// elements are considered similar, which makes the result the same as for [`TfIdf`].
+///
+/// The similarity isn't symmetric. Because of the inner similarity and threshold,
+/// it doesn't satisfy the triangle inequality either.
+///
+///     use textdistance::{Algorithm, SoftTfIdf};
+///     use textdistance::corpus::Corpus;
+///     let corpus: Corpus = ["acme inc", "globex inc", "initech inc"].into_iter().collect();
+///     let a = SoftTfIdf { corpus, ..Default::default() };
+///     let r1 = a.for_words("acme inc", "acmee corp").nval();
+///     let r2 = a.for_words("acme inc", "globex inc").nval();
+///     assert!(r1 > r2);
+///
+/// [Soft TF-IDF]: https://www.cs.cmu.edu/~wcohen/postscript/ijcai-ws-2003.pdf
+/// [`TfIdf`]: crate::TfIdf
+pub struct SoftTfIdf {
+    /// Document frequencies of elements. Default: empty.
+    pub corpus: Corpus,
+
+    /// The algorithm to calculate similarity of words. Default: [`JaroWinkler`] with default options.
+    pub jaro_winkler: JaroWinkler,
+
+    /// The minimal similarity of two words to consider them similar. Default: 0.9.
+    pub threshold: f64,
+}
+
+impl Default for SoftTfIdf {
+    fn default() -> Self {
+        Self {
+            corpus: Corpus::default(),
+            jaro_winkler: JaroWinkler::default(),
+            threshold: 0.9,
+        }
+    }
+}
+
+impl SoftTfIdf {
+    fn soft_tfidf<C, E, H, F>(&self, s1: C, s2: C, hash: H, sim: F) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+        H: Fn(&E) -> u64,
+        F: Fn(&E, &E) -> f64,
+    {
+        let mut len1 = 0;
+        let mut len2 = 0;
+        let w1 = self.corpus.weights(s1.inspect(|_| len1 += 1), &hash);
+        let w2 = self.corpus.weights(s2.inspect(|_| len2 += 1), &hash);
+        let res = match (w1.len(), w2.len()) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let mut total = 0.;
+                for (e1, v1) in &w1 {
+                    if let Some((s, v2)) = self.closest(e1, &w2, &sim) {
+                        total += v1 * v2 * s;
+                    }
+                }
+                total.min(1.)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1,
+            len2,
+        }
+    }
+
+    /// The similarity and the weight of the most similar element
+    /// if the similarity is at least the threshold.
+    fn closest<E, F>(&self, e1: &E, w2: &HashMap<E, f64>, sim: &F) -> Option<(f64, f64)>
+    where
+        E: Eq + Hash,
+        F: Fn(&E, &E) -> f64,
+    {
+        w2.iter()
+            .map(|(e2, v2)| (sim(e1, e2), *v2))
+            .filter(|(s, _)| *s > 0. && *s >= self.threshold)
+            // prefer the heavier element if similarities are equal
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+    }
+}
+
+impl Algorithm<f64> for SoftTfIdf {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+    {
+        self.soft_tfidf(s1, s2, std_hash, exact)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.soft_tfidf(s1.chars(), s2.chars(), stable_hash, exact)
+    }
+
+    fn for_words(&self, s1: &str, s2: &str) -> Result<f64> {
+        let sim = |w1: &&str, w2: &&str| self.jaro_winkler.for_str(w1, w2).nval();
+        self.soft_tfidf(
+            s1.split_whitespace(),
+            s2.split_whitespace(),
+            stable_hash,
+            sim,
+        )
+    }
+
+    fn for_bigrams(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.soft_tfidf(bigrams(s1), bigrams(s2), stable_hash, exact)
+    }
+}
+
+/// Similarity of elements that only match exactly.
+fn exact<E: Eq>(e1: &E, e2: &E) -> f64 {
+    if e1 == e2 {
+        1.
+    } else {
+        0.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::corpus::Corpus;
+    use crate::str::{soft_tfidf, tfidf};
+    use crate::{Algorithm, SoftTfIdf, TfIdf};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    fn corpus() -> Corpus {
+        ["acme inc", "globex inc", "initech inc", "umbrella ltd"]
+            .into_iter()
+            .collect()
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("", "neilsen", 0.)]
+    #[case("abc", "cba", 1.)]
+    #[case("abc", "def", 0.)]
+    #[case("test", "text", 0.833_333)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = soft_tfidf(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "soft_tfidf({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("acme inc", "acme inc", 1.)]
+    #[case("acme inc", "inc acme", 1.)]
+    #[case("acme inc", "acmee inc", 0.961_062)]
+    #[case("acme inc", "acme incorporated", 0.498_934)]
+    #[case("acme inc", "globex inc", 0.289_475)]
+    #[case("acme inc", "hooli", 0.)]
+    fn for_words(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = SoftTfIdf {
+            corpus: corpus(),
+            ..Default::default()
+        };
+        let act = a.for_words(s1, s2).nval();
+        let ok = is_close(act, exp);
+        assert!(ok, "for_words({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[test]
+    fn threshold() {
+        let a = SoftTfIdf {
+            corpus: corpus(),
+            threshold: 1.,
+            ..Default::default()
+        };
+        let t = TfIdf { corpus: corpus() };
+        let act = a.for_words("acme inc", "acmee inc").nval();
+        assert!(is_close(act, t.for_words("acme inc", "acmee inc").nval()));
+        assert!(act < 0.5);
+    }
+
+    #[test]
+    fn asymmetric() {
+        let a = SoftTfIdf::default();
+        let r1 = a.for_words("jon jonh", "john").nval();
+        let r2 = a.for_words("john", "jon jonh").nval();
+        assert!(r1 != r2);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = soft_tfidf(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, tfidf(&s1, &s2)));
+        }
+
+        #[test]
+        fn prop_words(s1 in "[a-c ]*", s2 in "[a-c ]*") {
+            let a = SoftTfIdf::default();
+            let res = a.for_words(&s1, &s2).nval();
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(res >= TfIdf::default().for_words(&s1, &s2).nval() - 1E-9);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Sokal-Michener similarity
+#![cfg(feature = "std")]
+use crate::counter::Counter;
+use crate::{Algorithm, Result};
+
+/// [Sokal-Michener similarity] (aka simple matching coefficient) is the number of matches
+/// divided by the number of all elements, where a match is an element that occurs
+/// in both sequences or in neither of them.
+///
+/// The elements that occur in neither of the sequences can be counted only if
+/// the `universe` of all possible elements is known. If it is not specified,
+/// the similarity is the same as [`Jaccard`].
+///
+/// [Sokal-Michener similarity]: https://en.wikipedia.org/wiki/Simple_matching_coefficient
+/// [`Jaccard`]: crate::Jaccard
+#[derive(Default)]
+pub struct SokalMichener {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+
+    /// The total number of unique elements that may occur in the sequences
+    /// (for example, 26 for English letters). Elements that occur in neither of the sequences
+    /// are counted as matches. Default: 0, no such elements.
+    pub universe: usize,
+}
+
+impl Algorithm<f64> for SokalMichener {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + core::hash::Hash,
+    {
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
+        let uc = c1.union_count(&c2);
+        let ic = c1.intersect_count(&c2);
+        // matches: elements that are in both sequences or in neither of them.
+        // The universe is a number of unique elements, so the ones in neither
+        // are counted as unique no matter if the sequences are multisets.
+        let absent = self.universe.saturating_sub(c1.merge(&c2).keys().count());
+        let matches = ic + absent;
+        let mismatches = uc - ic;
+        let total = matches + mismatches;
+        Result {
+            abs: if total == 0 {
+                1.
+            } else {
+                matches as f64 / total as f64
+            },
+            is_distance: false,
+            max: 1.,
+            len1: c1.count(),
+            len2: c2.count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::str::sokal_michener;
+    use crate::{Algorithm, SokalMichener};
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "abc", 0.)]
+    #[case("abc", "abc", 1.)]
+    #[case("test", "text", 0.6)]
+    #[case("nelson", "neilsen", 0.625)]
+    #[case("aaab", "ab", 0.5)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = sokal_michener(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "sokal_michener({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("test", "text", 0.5)]
+    #[case("nelson", "neilsen", 2. / 3.)]
+    #[case("aaab", "ab", 1.)]
+    fn as_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = SokalMichener {
+            as_set: true,
+            ..Default::default()
+        };
+        let act = a.for_str(s1, s2).nval();
+        assert!(
+            is_close(act, exp),
+            "sokal_michener({}, {}) is {}, not {}",
+            s1,
+            s2,
+            act,
+            exp
+        );
+    }
+
+    #[test]
+    fn universe() {
+        let a = SokalMichener {
+            universe: 26,
+            ..Default::default()
+        };
+        assert!(is_close(a.for_str("test", "text").nval(), 25. / 27.));
+        assert!(is_close(a.for_str("abc", "def").nval(), 20. / 26.));
+        // 2 matches of "a" and "b", 2 extra "a", and 24 letters in neither
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 26. / 28.));
+        assert!(is_close(a.for_str("aaaa", "aaaa").nval(), 1.));
+    }
+
+    #[test]
+    fn universe_as_set() {
+        let a = SokalMichener {
+            as_set: true,
+            universe: 26,
+        };
+        assert!(is_close(a.for_str("test", "text").nval(), 24. / 26.));
+        assert!(is_close(a.for_str("aaab", "ab").nval(), 1.));
+    }
+}
@@ -7,7 +7,11 @@ use crate::{Algorithm, Result};
 ///
 /// [Sørensen–Dice similarity]: https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient
 #[derive(Default)]
-pub struct SorensenDice {}
+pub struct SorensenDice {
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
+}
 
 impl Algorithm<f64> for SorensenDice {
     fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
@@ -15,8 +19,8 @@ impl Algorithm<f64> for SorensenDice {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let cn = c1.count() + c2.count();
         let res = if cn == 0 {
             1.
@@ -36,7 +40,7 @@ impl Algorithm<f64> for SorensenDice {
 
 #[cfg(test)]
 mod tests {
-    use crate::str::sorensen_dice;
+    use crate::str::{sorensen_dice, sorensen_dice_set};
     use crate::{Algorithm, SorensenDice};
     use assert2::assert;
     use rstest::rstest;
@@ -57,6 +61,23 @@ mod tests {
         assert!(ok, "sorensen_dice({}, {}) is {}, not {}", s1, s2, act, exp);
     }
 
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("aaab", "ab", 1.)]
+    // parity with textdistance (as_set=True)
+    #[case("test", "text", 2. / 3.)]
+    #[case("nelson", "neilsen", 0.8)]
+    fn function_str_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = sorensen_dice_set(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(
+            ok,
+            "sorensen_dice_set({}, {}) is {}, not {}",
+            s1, s2, act, exp
+        );
+    }
+
     #[rstest]
     // parity with strsim
     #[case("a", "a", 1.0)]
@@ -0,0 +1,275 @@
+//! Strcmp95 similarity
+use super::jaro::Jaro;
+use crate::{Algorithm, Result};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// Pairs of letters that are often confused by OCR or typing, and so are
+/// considered similar in [`Strcmp95`].
+const SIMILAR: [(u8, u8); 36] = [
+    (b'A', b'E'),
+    (b'A', b'I'),
+    (b'A', b'O'),
+    (b'A', b'U'),
+    (b'B', b'V'),
+    (b'E', b'I'),
+    (b'E', b'O'),
+    (b'E', b'U'),
+    (b'I', b'O'),
+    (b'I', b'U'),
+    (b'O', b'U'),
+    (b'I', b'Y'),
+    (b'E', b'Y'),
+    (b'C', b'G'),
+    (b'E', b'F'),
+    (b'W', b'U'),
+    (b'W', b'V'),
+    (b'X', b'K'),
+    (b'S', b'Z'),
+    (b'X', b'S'),
+    (b'Q', b'C'),
+    (b'U', b'V'),
+    (b'M', b'N'),
+    (b'L', b'I'),
+    (b'Q', b'O'),
+    (b'P', b'R'),
+    (b'I', b'J'),
+    (b'2', b'Z'),
+    (b'5', b'S'),
+    (b'8', b'B'),
+    (b'1', b'I'),
+    (b'1', b'L'),
+    (b'0', b'O'),
+    (b'0', b'Q'),
+    (b'C', b'K'),
+    (b'G', b'J'),
+];
+
+/// [Strcmp95] is the string comparator by Winkler used by the US Census Bureau
+/// for record linkage. It's [`JaroWinkler`] with two more adjustments:
+///
+/// 1. Unmatched characters that are often confused (like "O" and "0" or "I" and "L")
+///    count as 0.3 of a match.
+/// 2. Optionally, the similarity of long strings that agree beyond the common prefix
+///    is increased further (see `long_strings`).
+///
+/// Similar characters are known only for ASCII letters and digits in `for_str`,
+/// and the comparison there is case-insensitive and ignores leading and trailing whitespace.
+/// All other elements (including other methods of [`Algorithm`]) are compared only on equality.
+///
+///     use textdistance::{Algorithm, Strcmp95};
+///     let a = Strcmp95::default();
+///     assert!(a.for_str("SHACKLEFORD", "SHACKELFORD").nval() > 0.98);
+///     assert!(a.for_str("B0ND", "BOND").nval() > a.for_str("BXND", "BOND").nval());
+///
+/// [Strcmp95]: https://web.archive.org/web/20100227020019/http://www.census.gov/geo/msb/stand/strcmp.c
+/// [`JaroWinkler`]: crate::JaroWinkler
+#[derive(Default)]
+pub struct Strcmp95 {
+    /// If true, increase the similarity of strings longer than 4 characters
+    /// if more than half of the characters after the common prefix agree.
+    /// Default: false.
+    pub long_strings: bool,
+}
+
+impl Strcmp95 {
+    fn strcmp95<E, S, D>(&self, s1: &[E], s2: &[E], similar: S, is_digit: D) -> Result<f64>
+    where
+        E: Eq,
+        S: Fn(&E, &E) -> bool,
+        D: Fn(&E) -> bool,
+    {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let result = if l1 == 0 || l2 == 0 {
+            if l1 == 0 && l2 == 0 {
+                1.
+            } else {
+                0.
+            }
+        } else {
+            self.weight(s1, s2, similar, is_digit)
+        };
+        Result {
+            abs: result,
+            is_distance: false,
+            max: 1.,
+            len1: l1,
+            len2: l2,
+        }
+    }
+
+    fn weight<E, S, D>(&self, s1: &[E], s2: &[E], similar: S, is_digit: D) -> f64
+    where
+        E: Eq,
+        S: Fn(&E, &E) -> bool,
+        D: Fn(&E) -> bool,
+    {
+        let l1 = s1.len();
+        let l2 = s2.len();
+        let min_len = l1.min(l2);
+        let matches = Jaro::default().matches(s1, s2);
+        let n_com = matches.count;
+        if n_com == 0 {
+            return 0.;
+        }
+
+        // count half-transpositions: matched elements that are not in the same order
+        let matched2: Vec<&E> = s2
+            .iter()
+            .zip(&matches.flags2)
+            .filter_map(|(e, f)| f.then_some(e))
+            .collect();
+        let n_trans = s1
+            .iter()
+            .zip(&matches.flags1)
+            .filter_map(|(e, f)| f.then_some(e))
+            .zip(matched2)
+            .filter(|(e1, e2)| e1 != e2)
+            .count()
+            / 2;
+
+        // each pair of similar unmatched elements counts as 0.3 of a match
+        let mut n_simi: usize = 0;
+        if min_len > n_com {
+            let mut used2 = matches.flags2.clone();
+            for (e1, _) in s1.iter().zip(&matches.flags1).filter(|(_, f)| !**f) {
+                let found = s2
+                    .iter()
+                    .zip(&used2)
+                    .position(|(e2, used)| !used && similar(e1, e2));
+                if let Some(j) = found {
+                    n_simi += 3;
+                    used2[j] = true;
+                }
+            }
+        }
+
+        let n_sim = n_simi as f64 / 10. + n_com as f64;
+        let mut weight = n_sim / l1 as f64 + n_sim / l2 as f64;
+        weight += (n_com - n_trans) as f64 / n_com as f64;
+        weight /= 3.;
+        if weight <= 0.7 {
+            return weight;
+        }
+
+        // the common prefix of up to 4 non-digit elements
+        let prefix = s1
+            .iter()
+            .zip(s2)
+            .take(min_len.min(4))
+            .take_while(|(e1, e2)| e1 == e2 && !is_digit(e1))
+            .count();
+        weight += prefix as f64 * 0.1 * (1. - weight);
+
+        if self.long_strings
+            && min_len > 4
+            && n_com > prefix + 1
+            && 2 * n_com >= min_len + prefix
+            && !is_digit(&s1[0])
+        {
+            let rest = (n_com - prefix - 1) as f64 / (l1 + l2 - prefix * 2 + 2) as f64;
+            weight += (1. - weight) * rest;
+        }
+        weight
+    }
+}
+
+/// Check if the two ASCII characters are similar.
+fn similar(c1: char, c2: char) -> bool {
+    if !c1.is_ascii() || !c2.is_ascii() {
+        return false;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let (c1, c2) = (c1 as u8, c2 as u8);
+    SIMILAR.contains(&(c1, c2)) || SIMILAR.contains(&(c2, c1))
+}
+
+impl Algorithm<f64> for Strcmp95 {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<f64>
+    where
+        E: Eq + Hash,
+    {
+        self.strcmp95(s1, s2, |_, _| false, |_| false)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<f64> {
+        let s1: Vec<char> = s1.trim().chars().map(|c| c.to_ascii_uppercase()).collect();
+        let s2: Vec<char> = s2.trim().chars().map(|c| c.to_ascii_uppercase()).collect();
+        self.strcmp95(&s1, &s2, |c1, c2| similar(*c1, *c2), char::is_ascii_digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::str::strcmp95;
+    use crate::{Algorithm, JaroWinkler, Strcmp95};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("", "a", 0.)]
+    #[case("a", "", 0.)]
+    #[case("a", "a", 1.)]
+    #[case("abc", "xyz", 0.)]
+    // parity with textdistance
+    #[case("MARTHA", "MARHTA", 0.961_111)]
+    #[case("DWAYNE", "DUANE", 0.873)]
+    #[case("DIXON", "DICKSONX", 0.839_333)]
+    #[case("TEST", "TEXT", 0.906_667)]
+    // case and surrounding whitespace are ignored
+    #[case("martha", " MARHTA ", 0.961_111)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = strcmp95(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "strcmp95({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("MARTHA", "MARHTA", 0.970_833)]
+    #[case("DWAYNE", "DUANE", 0.896_091)]
+    #[case("DIXON", "DICKSONX", 0.853_939)]
+    // too short for the adjustment
+    #[case("TEST", "TEXT", 0.906_667)]
+    fn long_strings(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = Strcmp95 { long_strings: true };
+        let act = a.for_str(s1, s2).nval();
+        let ok = is_close(act, exp);
+        assert!(ok, "strcmp95({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("B0ND", "BOND")]
+    #[case("BILL", "B1LL")]
+    #[case("DWAYNE", "DUANE")]
+    fn similar_chars(#[case] s1: &str, #[case] s2: &str) {
+        let a = Strcmp95::default();
+        let act = a.for_str(s1, s2).nval();
+        assert!(act > a.for_vec(s1.as_bytes(), s2.as_bytes()).nval());
+        assert!(act == a.for_str(s2, s1).nval());
+    }
+
+    #[test]
+    fn digit_prefix() {
+        let a = Strcmp95::default();
+        let jw = JaroWinkler::default();
+        assert!(a.for_str("12345", "12354").nval() < jw.for_str("12345", "12354").nval());
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*", long_strings: bool) {
+            let a = Strcmp95 { long_strings };
+            let res = a.for_str(&s1, &s2).nval();
+            prop_assert!((0. ..=1.).contains(&res));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! TF-IDF cosine similarity
+#![cfg(feature = "std")]
+use crate::algorithm::bigrams;
+use crate::corpus::{stable_hash, std_hash, Corpus};
+use crate::{Algorithm, Result};
+use core::hash::Hash;
+
+/// [Cosine similarity] of [TF-IDF] weighted vectors of element counts.
+///
+/// Each element count is multiplied by the [inverse document frequency] of the element
+/// in the [`Corpus`], so elements that occur in many documents of the corpus have less
+/// influence on the similarity than rare ones. With an empty corpus (the default),
+/// all elements have the same weight.
+///
+///     use textdistance::{Algorithm, TfIdf};
+///     use textdistance::corpus::Corpus;
+///     let corpus: Corpus = ["acme inc", "globex inc", "initech inc"].into_iter().collect();
+///     let a = TfIdf { corpus };
+///     let r1 = a.for_words("acme inc", "acme corp").nval();
+///     let r2 = a.for_words("acme inc", "globex inc").nval();
+///     assert!(r1 > r2);
+///
+/// [Cosine similarity]: https://en.wikipedia.org/wiki/Cosine_similarity
+/// [TF-IDF]: https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+/// [inverse document frequency]: Corpus::idf
+#[derive(Default)]
+pub struct TfIdf {
+    /// Document frequencies of elements. Default: empty.
+    pub corpus: Corpus,
+}
+
+impl TfIdf {
+    fn tfidf<C, E, H>(&self, s1: C, s2: C, hash: H) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+        H: Fn(&E) -> u64,
+    {
+        let mut len1 = 0;
+        let mut len2 = 0;
+        let w1 = self.corpus.weights(s1.inspect(|_| len1 += 1), &hash);
+        let w2 = self.corpus.weights(s2.inspect(|_| len2 += 1), &hash);
+        let res = match (w1.len(), w2.len()) {
+            (0, 0) => 1.,
+            (_, 0) | (0, _) => 0.,
+            (_, _) => {
+                let dot: f64 = w1
+                    .iter()
+                    .filter_map(|(e, v1)| w2.get(e).map(|v2| v1 * v2))
+                    .sum();
+                dot.min(1.)
+            }
+        };
+        Result {
+            abs: res,
+            is_distance: false,
+            max: 1.,
+            len1,
+            len2,
+        }
+    }
+}
+
+impl Algorithm<f64> for TfIdf {
+    fn for_iter<C, E>(&self, s1: C, s2: C) -> Result<f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+    {
+        self.tfidf(s1, s2, std_hash)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.tfidf(s1.chars(), s2.chars(), stable_hash)
+    }
+
+    fn for_words(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.tfidf(s1.split_whitespace(), s2.split_whitespace(), stable_hash)
+    }
+
+    fn for_bigrams(&self, s1: &str, s2: &str) -> Result<f64> {
+        self.tfidf(bigrams(s1), bigrams(s2), stable_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::corpus::Corpus;
+    use crate::str::{cosine, tfidf};
+    use crate::{Algorithm, TfIdf};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    fn corpus() -> Corpus {
+        ["acme inc", "globex inc", "initech inc", "umbrella ltd"]
+            .into_iter()
+            .collect()
+    }
+
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("", "neilsen", 0.)]
+    #[case("abc", "cba", 1.)]
+    #[case("abc", "def", 0.)]
+    #[case("test", "text", 0.833_333)]
+    #[case("aab", "abb", 0.8)]
+    fn function_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = tfidf(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "tfidf({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[rstest]
+    #[case("acme inc", "acme inc", 1.)]
+    #[case("acme inc", "inc acme", 1.)]
+    #[case("acme inc", "acme", 0.842_926)]
+    #[case("acme inc", "inc", 0.538_029)]
+    #[case("acme inc", "globex inc", 0.289_475)]
+    #[case("acme inc", "acme ltd", 0.596_039)]
+    #[case("acme inc", "hooli", 0.)]
+    fn for_words(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let a = TfIdf { corpus: corpus() };
+        let act = a.for_words(s1, s2).nval();
+        let ok = is_close(act, exp);
+        assert!(ok, "for_words({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
+    #[test]
+    fn len() {
+        let res = TfIdf::default().for_str("aab", "b");
+        assert!(res.len1 == 3);
+        assert!(res.len2 == 1);
+    }
+
+    #[test]
+    fn incremental() {
+        let mut a = TfIdf::default();
+        let before = a.for_words("acme inc", "globex inc").nval();
+        a.corpus.extend(["initech inc", "hooli inc"]);
+        let after = a.for_words("acme inc", "globex inc").nval();
+        assert!(after < before);
+    }
+
+    proptest! {
+        #[test]
+        fn prop(s1 in ".*", s2 in ".*") {
+            let res = tfidf(&s1, &s2);
+            prop_assert!((0. ..=1.).contains(&res));
+            prop_assert!(is_close(res, tfidf(&s2, &s1)));
+        }
+
+        #[test]
+        fn prop_same(s in ".+") {
+            prop_assert!(is_close(tfidf(&s, &s), 1.));
+        }
+
+        #[test]
+        fn prop_disjoint(s1 in "[a-m]+", s2 in "[n-z]+") {
+            prop_assert!(tfidf(&s1, &s2) == 0.);
+            prop_assert!(cosine(&s1, &s2) == 0.);
+        }
+    }
+}
@@ -15,6 +15,10 @@ pub struct Tversky {
     pub beta: f64,
     /// The symmetric Tversky index bias parameter.
     pub bias: f64,
+
+    /// If true, count each unique element only once,
+    /// so that the sequences are compared as sets instead of multisets. Default: false.
+    pub as_set: bool,
 }
 
 impl Default for Tversky {
@@ -23,6 +27,7 @@ impl Default for Tversky {
             alpha: 1.,
             beta: 1.,
             bias: 0.,
+            as_set: false,
         }
     }
 }
@@ -33,8 +38,8 @@ impl Algorithm<f64> for Tversky {
         C: Iterator<Item = E>,
         E: Eq + core::hash::Hash,
     {
-        let c1 = Counter::from_iter(s1);
-        let c2 = Counter::from_iter(s2);
+        let c1 = Counter::from_iter_mode(s1, self.as_set);
+        let c2 = Counter::from_iter_mode(s2, self.as_set);
         let ic = c1.intersect_count(&c2);
         let n1 = c1.count();
         let n2 = c2.count();
@@ -64,7 +69,7 @@ impl Algorithm<f64> for Tversky {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::str::{jaccard, sorensen_dice, tversky};
+    use crate::str::{jaccard, sorensen_dice, tversky, tversky_set};
     use assert2::assert;
     use proptest::prelude::*;
     use rstest::rstest;
@@ -83,6 +88,19 @@ mod tests {
         assert!(ok, "tversky({}, {}) is {}, not {}", s1, s2, act, exp);
     }
 
+    #[rstest]
+    #[case("", "", 1.)]
+    #[case("nelson", "", 0.)]
+    #[case("aaab", "ab", 1.)]
+    // parity with textdistance (as_set=True)
+    #[case("test", "text", 0.5)]
+    #[case("nelson", "neilsen", 4. / 6.)]
+    fn function_str_set(#[case] s1: &str, #[case] s2: &str, #[case] exp: f64) {
+        let act = tversky_set(s1, s2);
+        let ok = is_close(act, exp);
+        assert!(ok, "tversky_set({}, {}) is {}, not {}", s1, s2, act, exp);
+    }
+
     proptest! {
         #[test]
         fn sorensen_dice_eqivalence(s1 in ".*", s2 in ".*") {
@@ -0,0 +1,523 @@
+//! Levenshtein automata for searching in dictionaries.
+//!
+//! Comparing a query with each word of a big dictionary is slow. A [`Dfa`] built
+//! for the query instead reads a word one character at a time and knows after each
+//! character whether any word starting like this can be within the distance.
+//! So it can walk a sorted word list or a [`Trie`] and skip everything else.
+//!
+//!     use textdistance::automaton::LevenshteinAutomaton;
+//!
+//!     let a = LevenshteinAutomaton { max_distance: 1, ..Default::default() };
+//!     let dfa = a.build("sitting");
+//!     let words = ["fitting", "kitten", "setting", "sitting", "spitting"];
+//!     let found = dfa.filter_sorted(&words);
+//!     assert!(found == vec![("fitting", 1), ("setting", 1), ("sitting", 0), ("spitting", 1)]);
+//!
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Builds a deterministic [Levenshtein automaton] for a query.
+///
+/// The automaton accepts all words within `max_distance` from the query.
+/// It's built using the method of Schulz and Mihov: each state is a set of positions
+/// in the query with the number of errors so far, with the positions that can't
+/// give a better result than the others removed. All states are built in advance,
+/// for each distinct character of the query and for all other characters at once,
+/// so the number of states is linear in the length of the query.
+/// The automaton grows quickly with the distance and is practical for `max_distance` up to 3.
+///
+/// [Levenshtein automaton]: https://en.wikipedia.org/wiki/Levenshtein_automaton
+pub struct LevenshteinAutomaton {
+    /// The maximum distance between the query and the accepted words.
+    /// Default: 1.
+    pub max_distance: usize,
+
+    /// If true, swapping two adjacent characters counts as one error,
+    /// and the distance is the same as of
+    /// [`DamerauLevenshtein`](crate::DamerauLevenshtein) with `restricted: true`.
+    /// Otherwise, it's the same as of [`Levenshtein`](crate::Levenshtein).
+    /// Default: false.
+    pub transpositions: bool,
+
+    /// If true, accept words that start with a match for the query, for autocompletion.
+    /// The distance is the smallest distance between the query and a prefix of the word.
+    /// Default: false.
+    pub prefix: bool,
+}
+
+impl Default for LevenshteinAutomaton {
+    fn default() -> Self {
+        Self {
+            max_distance: 1,
+            transpositions: false,
+            prefix: false,
+        }
+    }
+}
+
+/// A position of a nondeterministic automaton: `i` characters of the query
+/// are read with `e` errors. If `t` is true, the characters `i` and `i + 1`
+/// are being swapped, and `i + 1` is already read.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Position {
+    i: usize,
+    e: usize,
+    t: bool,
+}
+
+impl Position {
+    fn new(i: usize, e: usize) -> Self {
+        Self { i, e, t: false }
+    }
+}
+
+impl LevenshteinAutomaton {
+    /// Build the automaton for the query.
+    pub fn build(&self, query: &str) -> Dfa {
+        let query: Vec<char> = query.chars().collect();
+        let mut alphabet = query.clone();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+
+        let start = vec![Position::new(0, 0)];
+        let mut ids: BTreeMap<Vec<Position>, usize> = BTreeMap::new();
+        ids.insert(start.clone(), 0);
+        let mut states = vec![start];
+        let mut transitions = Vec::new();
+        let mut distances = Vec::new();
+        let mut idx = 0;
+        while idx < states.len() {
+            let state = states[idx].clone();
+            distances.push(self.distance(&query, &state));
+            // the last class is for all characters not in the query
+            for c in alphabet.iter().copied().map(Some).chain([None]) {
+                let next = self.next(&query, &state, c);
+                let id = if let Some(id) = ids.get(&next) {
+                    *id
+                } else {
+                    ids.insert(next.clone(), states.len());
+                    states.push(next);
+                    states.len() - 1
+                };
+                transitions.push(id);
+            }
+            idx += 1;
+        }
+        let live = states.iter().map(|s| !s.is_empty()).collect();
+        Dfa {
+            alphabet,
+            transitions,
+            distances,
+            live,
+        }
+    }
+
+    /// The positions after reading the character.
+    fn next(&self, query: &[char], state: &[Position], c: Option<char>) -> Vec<Position> {
+        let len = query.len();
+        let max = self.max_distance;
+        let is = |i: usize| i < len && Some(query[i]) == c;
+        let mut next = Vec::new();
+        for p in state {
+            if p.t {
+                if is(p.i) {
+                    next.push(Position::new(p.i + 2, p.e));
+                }
+                continue;
+            }
+            if p.e < max {
+                // insertion
+                next.push(Position::new(p.i, p.e + 1));
+                // substitution
+                if p.i < len {
+                    next.push(Position::new(p.i + 1, p.e + 1));
+                }
+            }
+            // a match, maybe after deleting a few characters of the query
+            for j in 0..=max - p.e {
+                let i = p.i + j;
+                let e = p.e + j;
+                if i == len {
+                    if self.prefix {
+                        next.push(Position::new(len, e));
+                    }
+                    break;
+                }
+                if is(i) {
+                    next.push(Position::new(i + 1, e));
+                }
+                if self.transpositions && e < max && is(i + 1) {
+                    next.push(Position {
+                        i,
+                        e: e + 1,
+                        t: true,
+                    });
+                }
+            }
+        }
+        next.sort_unstable();
+        next.dedup();
+        next.iter()
+            .filter(|b| !next.iter().any(|a| self.subsumes(a, b)))
+            .copied()
+            .collect()
+    }
+
+    /// Check if every word accepted from `b` is also accepted from `a` with fewer errors.
+    fn subsumes(&self, a: &Position, b: &Position) -> bool {
+        if a.t || b.t || a.e >= b.e {
+            return false;
+        }
+        // With transpositions, inserting characters in front of the query and then
+        // swapping them might be not allowed, so only deletions are considered.
+        if self.transpositions && b.i < a.i {
+            return false;
+        }
+        a.i.abs_diff(b.i) <= b.e - a.e
+    }
+
+    /// The distance for the state if the word ends here.
+    fn distance(&self, query: &[char], state: &[Position]) -> Option<usize> {
+        state
+            .iter()
+            .filter(|p| !p.t)
+            .map(|p| p.e + query.len() - p.i)
+            .filter(|d| *d <= self.max_distance)
+            .min()
+    }
+}
+
+/// A state of a [`Dfa`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct State(usize);
+
+/// A deterministic Levenshtein automaton built by [`LevenshteinAutomaton::build`].
+pub struct Dfa {
+    /// Sorted distinct characters of the query.
+    alphabet: Vec<char>,
+    /// For each state, the next state for each character of the alphabet
+    /// and then for all other characters.
+    transitions: Vec<usize>,
+    distances: Vec<Option<usize>>,
+    live: Vec<bool>,
+}
+
+impl Dfa {
+    /// The state before reading any characters.
+    pub fn start(&self) -> State {
+        State(0)
+    }
+
+    /// The state after reading the character.
+    ///
+    ///     use textdistance::automaton::LevenshteinAutomaton;
+    ///
+    ///     let dfa = LevenshteinAutomaton::default().build("abc");
+    ///     let mut state = dfa.start();
+    ///     for c in "xbc".chars() {
+    ///         state = dfa.step(state, c);
+    ///     }
+    ///     assert!(dfa.distance(state) == Some(1));
+    ///     assert!(!dfa.can_match(dfa.step(state, 'y')));
+    ///
+    pub fn step(&self, state: State, c: char) -> State {
+        let width = self.alphabet.len() + 1;
+        let class = self
+            .alphabet
+            .binary_search(&c)
+            .unwrap_or(self.alphabet.len());
+        State(self.transitions[state.0 * width + class])
+    }
+
+    /// The distance between the query and the characters read so far,
+    /// or `None` if it's more than the maximum distance.
+    pub fn distance(&self, state: State) -> Option<usize> {
+        self.distances[state.0]
+    }
+
+    /// Check if reading more characters can lead to a match.
+    ///
+    /// If not, the state will never change, and no word starting
+    /// with the characters read so far is accepted.
+    pub fn can_match(&self, state: State) -> bool {
+        self.live[state.0]
+    }
+
+    /// The distance between the query and the word,
+    /// or `None` if it's more than the maximum distance.
+    pub fn eval(&self, word: &str) -> Option<usize> {
+        let mut state = self.start();
+        for c in word.chars() {
+            state = self.step(state, c);
+            if !self.can_match(state) {
+                return None;
+            }
+        }
+        self.distance(state)
+    }
+
+    /// Find all accepted words in a sorted list, with their distances.
+    ///
+    /// The states for the common prefix of neighboring words are reused,
+    /// and when a prefix can't match, all words starting with it are skipped
+    /// using binary search. If the list isn't sorted, some words might be missed.
+    pub fn filter_sorted<'a, S: AsRef<str>>(&self, words: &'a [S]) -> Vec<(&'a str, usize)> {
+        let mut found = Vec::new();
+        // the states after each character of the previous word
+        let mut stack = vec![self.start()];
+        let mut prev = "";
+        let mut idx = 0;
+        while idx < words.len() {
+            let word = words[idx].as_ref();
+            let common = word
+                .chars()
+                .zip(prev.chars())
+                .take_while(|(c1, c2)| c1 == c2)
+                .count();
+            stack.truncate(common.min(stack.len() - 1) + 1);
+            prev = word;
+            let mut dead = None;
+            for (pos, c) in word.char_indices().skip(stack.len() - 1) {
+                let state = self.step(stack[stack.len() - 1], c);
+                if !self.can_match(state) {
+                    dead = Some(pos + c.len_utf8());
+                    break;
+                }
+                stack.push(state);
+            }
+            if let Some(end) = dead {
+                let prefix = &word[..end];
+                idx += words[idx..].partition_point(|w| w.as_ref().starts_with(prefix));
+                continue;
+            }
+            if let Some(distance) = self.distance(stack[stack.len() - 1]) {
+                found.push((word, distance));
+            }
+            idx += 1;
+        }
+        found
+    }
+
+    /// Find all accepted words in the trie, in sorted order, with their distances.
+    ///
+    ///     use textdistance::automaton::{LevenshteinAutomaton, Trie};
+    ///
+    ///     let trie: Trie = ["apple", "apply", "ape", "maple"].into_iter().collect();
+    ///     let a = LevenshteinAutomaton { max_distance: 1, prefix: true, ..Default::default() };
+    ///     let found = a.build("appl").search_trie(&trie);
+    ///     assert!(found == vec![("apple".to_string(), 0), ("apply".to_string(), 0)]);
+    ///
+    pub fn search_trie(&self, trie: &Trie) -> Vec<(String, usize)> {
+        let mut found = Vec::new();
+        self.walk(trie, self.start(), &mut String::new(), &mut found);
+        found
+    }
+
+    fn walk(&self, node: &Trie, state: State, word: &mut String, found: &mut Vec<(String, usize)>) {
+        if node.word {
+            if let Some(distance) = self.distance(state) {
+                found.push((word.clone(), distance));
+            }
+        }
+        for (c, child) in &node.children {
+            let next = self.step(state, *c);
+            if !self.can_match(next) {
+                continue;
+            }
+            word.push(*c);
+            self.walk(child, next, word, found);
+            word.pop();
+        }
+    }
+}
+
+/// A set of words stored as a tree of characters, for [`Dfa::search_trie`].
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct Trie {
+    children: BTreeMap<char, Trie>,
+    word: bool,
+}
+
+impl Trie {
+    /// Create an empty trie.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the word to the trie.
+    pub fn insert(&mut self, word: &str) {
+        let mut node = self;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.word = true;
+    }
+
+    /// Check if the word is in the trie.
+    pub fn contains(&self, word: &str) -> bool {
+        self.get(word).is_some_and(|node| node.word)
+    }
+
+    /// The subtree of words starting with the prefix.
+    pub fn get(&self, prefix: &str) -> Option<&Trie> {
+        let mut node = self;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    /// Check if the path from the root to this node is a word.
+    pub fn is_word(&self) -> bool {
+        self.word
+    }
+
+    /// The next characters of the words and their subtrees, in sorted order.
+    pub fn children(&self) -> impl Iterator<Item = (char, &Trie)> {
+        self.children.iter().map(|(c, node)| (*c, node))
+    }
+}
+
+impl<'a> Extend<&'a str> for Trie {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Algorithm, DamerauLevenshtein, Levenshtein};
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn osa(s1: &str, s2: &str) -> usize {
+        let a = DamerauLevenshtein {
+            restricted: true,
+            ..Default::default()
+        };
+        a.for_str(s1, s2).val()
+    }
+
+    fn lev(s1: &str, s2: &str) -> usize {
+        Levenshtein::default().for_str(s1, s2).val()
+    }
+
+    #[rstest]
+    #[case("", "", 1, Some(0))]
+    #[case("", "a", 1, Some(1))]
+    #[case("", "ab", 1, None)]
+    #[case("abc", "abc", 0, Some(0))]
+    #[case("abc", "abd", 0, None)]
+    #[case("abc", "abd", 1, Some(1))]
+    #[case("abc", "ac", 1, Some(1))]
+    #[case("abc", "abcd", 1, Some(1))]
+    #[case("abc", "acb", 1, None)]
+    #[case("abc", "acb", 2, Some(2))]
+    #[case("kitten", "sitting", 2, None)]
+    #[case("kitten", "sitting", 3, Some(3))]
+    #[case("привет", "превед", 2, Some(2))]
+    fn eval(#[case] query: &str, #[case] word: &str, #[case] k: usize, #[case] exp: Option<usize>) {
+        let a = LevenshteinAutomaton {
+            max_distance: k,
+            ..Default::default()
+        };
+        assert!(a.build(query).eval(word) == exp);
+    }
+
+    #[rstest]
+    #[case("abc", "acb", 1, Some(1))]
+    #[case("abcd", "badc", 2, Some(2))]
+    #[case("ca", "abc", 2, None)]
+    #[case("ca", "abc", 3, Some(3))]
+    fn transpositions(
+        #[case] query: &str,
+        #[case] word: &str,
+        #[case] k: usize,
+        #[case] exp: Option<usize>,
+    ) {
+        let a = LevenshteinAutomaton {
+            max_distance: k,
+            transpositions: true,
+            ..Default::default()
+        };
+        assert!(a.build(query).eval(word) == exp);
+    }
+
+    #[rstest]
+    #[case("app", "apple", Some(0))]
+    #[case("app", "aple", Some(1))]
+    #[case("app", "banana", None)]
+    #[case("app", "ap", Some(1))]
+    fn prefix(#[case] query: &str, #[case] word: &str, #[case] exp: Option<usize>) {
+        let a = LevenshteinAutomaton {
+            prefix: true,
+            ..Default::default()
+        };
+        assert!(a.build(query).eval(word) == exp);
+    }
+
+    #[test]
+    fn trie() {
+        let mut trie: Trie = ["ab", "abc"].into_iter().collect();
+        trie.insert("b");
+        assert!(trie.contains("ab"));
+        assert!(!trie.contains("a"));
+        assert!(!trie.contains("abcd"));
+        let chars: Vec<char> = trie.children().map(|(c, _)| c).collect();
+        assert!(chars == vec!['a', 'b']);
+        assert!(trie.get("a").is_some_and(|node| !node.is_word()));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_eval(query in "[abc]{0,6}", word in "[abcd]{0,8}", k in 0usize..4, transpositions: bool) {
+            let a = LevenshteinAutomaton { max_distance: k, transpositions, prefix: false };
+            let d = if transpositions { osa(&query, &word) } else { lev(&query, &word) };
+            let exp = (d <= k).then_some(d);
+            prop_assert!(a.build(&query).eval(&word) == exp);
+        }
+
+        #[test]
+        fn prop_prefix(query in "[abc]{0,6}", word in "[abcd]{0,8}", k in 0usize..4, transpositions: bool) {
+            let a = LevenshteinAutomaton { max_distance: k, transpositions, prefix: true };
+            let d = (0..=word.len())
+                .map(|end| if transpositions { osa(&query, &word[..end]) } else { lev(&query, &word[..end]) })
+                .min()
+                .unwrap();
+            let exp = (d <= k).then_some(d);
+            prop_assert!(a.build(&query).eval(&word) == exp);
+        }
+
+        #[test]
+        fn prop_dictionary(query in "[abc]{0,5}", words in prop::collection::vec("[abcd]{0,6}", 0..30), k in 0usize..3) {
+            let a = LevenshteinAutomaton { max_distance: k, ..Default::default() };
+            let dfa = a.build(&query);
+            let mut words = words;
+            words.sort();
+            words.dedup();
+            let exp: Vec<(&str, usize)> = words
+                .iter()
+                .filter_map(|w| dfa.eval(w).map(|d| (w.as_str(), d)))
+                .collect();
+            prop_assert!(dfa.filter_sorted(&words) == exp.clone());
+            let trie: Trie = words.iter().map(String::as_str).collect();
+            let found = dfa.search_trie(&trie);
+            let found: Vec<(&str, usize)> = found.iter().map(|(w, d)| (w.as_str(), *d)).collect();
+            prop_assert!(found == exp);
+        }
+    }
+}
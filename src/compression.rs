@@ -0,0 +1,212 @@
+//! Compression-based distance.
+//!
+//! [Normalized Compression Distance] (NCD) measures how much better two inputs are compressed
+//! together than each of them separately. If the inputs are similar, the compressor can reuse
+//! the information from the first one when compressing the second one.
+//!
+//! This module provides the generic [`NCD`] algorithm and the built-in [`Compressor`]s
+//! it can be used with. There are also shortcuts for each of them: [`ArithNCD`],
+//! [`BWTRLENCD`], and [`LZ77NCD`].
+//!
+//!     use textdistance::Algorithm;
+//!     use textdistance::compression::{NCD, LZ77};
+//!
+//!     let a = NCD::new(LZ77::default());
+//!     let r1 = a.for_str("the quick brown fox", "the quick brown dog").nval();
+//!     let r2 = a.for_str("the quick brown fox", "jumps over the lazy").nval();
+//!     assert!(r1 < r2);
+//!
+//! You can plug in any other compressor by implementing the [`Compressor`] trait:
+//!
+//!     use textdistance::Algorithm;
+//!     use textdistance::compression::{Compressor, NCD};
+//!
+//!     /// Each unique byte costs 8 bits.
+//!     struct Unique;
+//!
+//!     impl Compressor for Unique {
+//!         fn compressed_size(&self, data: &[u8]) -> f64 {
+//!             let mut seen = [false; 256];
+//!             for b in data {
+//!                 seen[*b as usize] = true;
+//!             }
+//!             seen.iter().filter(|s| **s).count() as f64 * 8.
+//!         }
+//!     }
+//!
+//!     let a = NCD::new(Unique);
+//!     assert!(a.for_str("abc", "cab").nval() == 0.);
+//!     assert!(a.for_str("abc", "abd").nval() == 1. / 3.);
+//!
+//! [Normalized Compression Distance]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+//! [`ArithNCD`]: crate::ArithNCD
+//! [`BWTRLENCD`]: crate::BWTRLENCD
+//! [`LZ77NCD`]: crate::LZ77NCD
+#![cfg(feature = "std")]
+use crate::{Algorithm, Result};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+pub use crate::algorithms::arith_ncd::ArithCoder;
+pub use crate::algorithms::bwtrle_ncd::BWTRLE;
+pub use crate::algorithms::lz77_ncd::LZ77;
+
+/// A base trait for all compressors that can be used with [`NCD`].
+pub trait Compressor {
+    /// The size (in bits) of the given data after compression.
+    ///
+    /// The compressor doesn't need to actually produce the compressed data,
+    /// an estimation is enough as long as it's consistent.
+    fn compressed_size(&self, data: &[u8]) -> f64;
+}
+
+/// [Normalized Compression Distance] based on the given [`Compressor`].
+///
+/// The distance is `(C(xy) - min(C(x), C(y))) / max(C(x), C(y))` where `C` is the compressed size.
+/// Since real compressors aren't perfect, the result is clamped to the 0.0-1.0 interval.
+///
+/// `for_str` compresses UTF-8 bytes of the strings. Other methods of [`Algorithm`]
+/// replace each unique element by a number before compression.
+///
+/// [Normalized Compression Distance]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+pub struct NCD<C> {
+    /// The compressor to calculate the compressed size of inputs.
+    pub compressor: C,
+}
+
+impl<C: Compressor> NCD<C> {
+    /// Make a new algorithm using the given compressor.
+    pub fn new(compressor: C) -> Self {
+        Self { compressor }
+    }
+
+    fn ncd(&self, s1: &[u8], s2: &[u8], len1: usize, len2: usize) -> Result<f64> {
+        let c1 = self.compressor.compressed_size(s1);
+        let c2 = self.compressor.compressed_size(s2);
+        let max = c1.max(c2);
+        let res = if max == 0. {
+            0.
+        } else {
+            let mut joined = Vec::with_capacity(s1.len() + s2.len());
+            joined.extend_from_slice(s1);
+            joined.extend_from_slice(s2);
+            let c12 = self.compressor.compressed_size(&joined);
+            joined.clear();
+            joined.extend_from_slice(s2);
+            joined.extend_from_slice(s1);
+            let c21 = self.compressor.compressed_size(&joined);
+            ((c12.min(c21) - c1.min(c2)) / max).clamp(0., 1.)
+        };
+        Result {
+            abs: res,
+            is_distance: true,
+            max: 1.,
+            len1,
+            len2,
+        }
+    }
+}
+
+impl<C: Default> Default for NCD<C> {
+    fn default() -> Self {
+        Self {
+            compressor: C::default(),
+        }
+    }
+}
+
+/// Encode each element as its ID in [LEB128] format.
+///
+/// The same elements get the same IDs in both sequences.
+///
+/// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+fn encode<'a, E: Eq + Hash>(s: &'a [E], ids: &mut HashMap<&'a E, usize>) -> Vec<u8> {
+    let mut result = Vec::with_capacity(s.len());
+    for e in s {
+        let next_id = ids.len();
+        let mut id = *ids.entry(e).or_insert(next_id);
+        loop {
+            #[allow(clippy::cast_possible_truncation)]
+            let byte = (id & 0x7F) as u8;
+            id >>= 7;
+            if id == 0 {
+                result.push(byte);
+                break;
+            }
+            result.push(byte | 0x80);
+        }
+    }
+    result
+}
+
+impl<C: Compressor> Algorithm<f64> for NCD<C> {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<f64>
+    where
+        E: Eq + Hash,
+    {
+        let mut ids = HashMap::new();
+        let b1 = encode(s1, &mut ids);
+        let b2 = encode(s2, &mut ids);
+        self.ncd(&b1, &b2, s1.len(), s2.len())
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<f64> {
+        let len1 = s1.chars().count();
+        let len2 = s2.chars().count();
+        self.ncd(s1.as_bytes(), s2.as_bytes(), len1, len2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    /// Each unique byte costs 8 bits.
+    struct Unique;
+
+    impl Compressor for Unique {
+        fn compressed_size(&self, data: &[u8]) -> f64 {
+            let mut seen = [false; 256];
+            for b in data {
+                seen[*b as usize] = true;
+            }
+            seen.iter().filter(|s| **s).count() as f64 * 8.
+        }
+    }
+
+    #[rstest]
+    #[case(&[], &[])]
+    #[case(&[7, 8], &[0x00, 0x01])]
+    #[case(&[7, 7, 8, 7], &[0x00, 0x00, 0x01, 0x00])]
+    fn encode_ids(#[case] s: &[usize], #[case] exp: &[u8]) {
+        let act = encode(s, &mut HashMap::new());
+        assert!(act == exp);
+    }
+
+    #[test]
+    fn leb128() {
+        let s: Vec<usize> = (0..200).collect();
+        let mut ids = HashMap::new();
+        let act = encode(&s, &mut ids);
+        assert!(act[127] == 0x7F);
+        assert!(act[128..130] == [0x80, 0x01]);
+        assert!(act.len() == 128 + 72 * 2);
+    }
+
+    #[test]
+    fn custom() {
+        let a = NCD::new(Unique);
+        assert!(a.for_str("", "").nval() == 0.);
+        assert!(a.for_str("", "abc").nval() == 1.);
+        assert!(a.for_str("abc", "cab").nval() == 0.);
+        assert!(a.for_str("ab", "cdef").nval() == 1.);
+        assert!(a.for_str("abcd", "abef").nval() == 0.5);
+        assert!(a.for_vec(&[1, 2], &[2, 1]).nval() == 0.);
+        assert!(a.for_vec(&[1, 2], &[3, 4]).nval() == 1.);
+        assert!(a.for_str("abc", "cab").len2 == 3);
+    }
+}
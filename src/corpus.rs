@@ -0,0 +1,593 @@
+//! Corpus statistics for token weighting.
+//!
+//! Some tokens are so common that they tell nothing about similarity of two texts.
+//! For example, "inc" and "ltd" in company names. [`Corpus`] collects [document frequencies]
+//! of tokens from a collection of texts, and corpus-aware algorithms ([`TfIdf`] and
+//! [`SoftTfIdf`]) use it to give rare tokens more weight than common ones.
+//!
+//!     use textdistance::{Algorithm, TfIdf};
+//!     use textdistance::corpus::Corpus;
+//!
+//!     let corpus: Corpus = [
+//!         "acme inc",
+//!         "globex inc",
+//!         "initech inc",
+//!         "umbrella ltd",
+//!     ].into_iter().collect();
+//!     let a = TfIdf { corpus };
+//!     let r1 = a.for_words("acme inc", "acme ltd").nval();
+//!     let r2 = a.for_words("acme inc", "globex inc").nval();
+//!     assert!(r1 > r2);
+//!
+//! The corpus can be updated incrementally, and the statistics can be saved
+//! as a string and loaded back:
+//!
+//!     use textdistance::corpus::Corpus;
+//!
+//!     let mut corpus = Corpus::new();
+//!     corpus.add_words("acme inc");
+//!     corpus.extend(["globex inc", "initech inc"]);
+//!     assert!(corpus.docs() == 3);
+//!     assert!(corpus.df("inc") == 3);
+//!
+//!     let saved = corpus.to_string();
+//!     let loaded: Corpus = saved.parse().unwrap();
+//!     assert!(loaded == corpus);
+//!
+//! Tokens are stored as 64-bit hashes of their [`StableHash`] bytes, so the same corpus
+//! can be built for and applied to any token type (words, characters, bigrams, numbers)
+//! as long as it's the same type in both cases. The algorithms use these hashes
+//! for [`Algorithm::for_str`], [`Algorithm::for_words`], and [`Algorithm::for_bigrams`].
+//! Generic [`Algorithm::for_iter`] and [`Algorithm::for_vec`] accept any [`Hash`] tokens,
+//! so they feed the std [`Hash`] values into the same hasher instead, with integers
+//! as little-endian bytes. For the built-in types, the result is currently the same
+//! on all platforms, but it isn't guaranteed for future Rust versions.
+//!
+//! [document frequencies]: https://en.wikipedia.org/wiki/Document_frequency
+//! [`TfIdf`]: crate::TfIdf
+//! [`SoftTfIdf`]: crate::SoftTfIdf
+//! [`Algorithm::for_str`]: crate::Algorithm::for_str
+//! [`Algorithm::for_words`]: crate::Algorithm::for_words
+//! [`Algorithm::for_bigrams`]: crate::Algorithm::for_bigrams
+//! [`Algorithm::for_iter`]: crate::Algorithm::for_iter
+//! [`Algorithm::for_vec`]: crate::Algorithm::for_vec
+#![cfg(feature = "std")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::ParseIntError;
+use core::str::FromStr;
+use std::collections::{HashMap, HashSet};
+
+/// [FNV-1a] hasher for [`StableHash`] tokens. Unlike the std hasher, it produces
+/// the same hashes on all platforms and in all versions,
+/// which is required to save and load the statistics.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+#[derive(Clone, Copy, Debug)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    /// Make a hasher with the standard FNV-1a offset basis.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the bytes into the hasher.
+    pub fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// The hash of all bytes written so far.
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+/// A token that can be hashed into a [`Corpus`].
+///
+/// The implementation must feed the same canonical bytes into the hasher
+/// on all platforms, so that the saved statistics can be loaded anywhere.
+/// The built-in implementations write integers as little-endian bytes
+/// (`usize` and `isize` as 64-bit), chars as `u32`, strings as their UTF-8 bytes
+/// followed by `0xff`, and slices as their length followed by the elements.
+///
+///     use textdistance::corpus::{Corpus, StableHash, StableHasher};
+///
+///     #[derive(PartialEq, Eq, Hash)]
+///     struct Tag(u8);
+///
+///     impl StableHash for Tag {
+///         fn stable_hash(&self, hasher: &mut StableHasher) {
+///             hasher.write(&[self.0]);
+///         }
+///     }
+///
+///     let mut corpus = Corpus::new();
+///     corpus.add([Tag(1), Tag(2), Tag(1)]);
+///     assert!(corpus.df(&Tag(1)) == 1);
+///
+pub trait StableHash {
+    /// Feed the canonical bytes of the value into the hasher.
+    fn stable_hash(&self, hasher: &mut StableHasher);
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl StableHash for $t {
+                fn stable_hash(&self, hasher: &mut StableHasher) {
+                    hasher.write(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StableHash for usize {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (*self as u64).stable_hash(hasher);
+    }
+}
+
+impl StableHash for isize {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (*self as i64).stable_hash(hasher);
+    }
+}
+
+impl StableHash for bool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        u8::from(*self).stable_hash(hasher);
+    }
+}
+
+impl StableHash for char {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        u32::from(*self).stable_hash(hasher);
+    }
+}
+
+impl StableHash for str {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.write(self.as_bytes());
+        // 0xff never occurs in UTF-8, so ("ab", "c") and ("a", "bc") differ
+        hasher.write(&[0xff]);
+    }
+}
+
+impl StableHash for String {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+impl<T: StableHash> StableHash for [T] {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.len().stable_hash(hasher);
+        for e in self {
+            e.stable_hash(hasher);
+        }
+    }
+}
+
+impl<T: StableHash> StableHash for Vec<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_hash(hasher);
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for &T {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+impl<A: StableHash, B: StableHash> StableHash for (A, B) {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.0.stable_hash(hasher);
+        self.1.stable_hash(hasher);
+    }
+}
+
+impl<A: StableHash, B: StableHash, C: StableHash> StableHash for (A, B, C) {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.0.stable_hash(hasher);
+        self.1.stable_hash(hasher);
+        self.2.stable_hash(hasher);
+    }
+}
+
+/// The stable hash of the token.
+pub(crate) fn stable_hash<E: StableHash + ?Sized>(token: &E) -> u64 {
+    let mut hasher = StableHasher::new();
+    token.stable_hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Adapter feeding the std [`Hash`] byte stream into [`StableHasher`].
+struct StdHasher(StableHasher);
+
+impl Hasher for StdHasher {
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+
+    // The default implementations use native-endian bytes,
+    // write little-endian ones like the built-in `StableHash` implementations.
+    // Signed integers go through these methods too.
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// The hash of the token through its std [`Hash`] implementation.
+///
+/// Integers are written as little-endian bytes on all platforms, so for the built-in types
+/// it is currently the same as [`stable_hash`]. However, which values the std implementations
+/// write may change between Rust versions, so a corpus built this way should be used
+/// with the same Rust version. It's used only for tokens of generic [`Algorithm::for_iter`] calls.
+///
+/// [`Algorithm::for_iter`]: crate::Algorithm::for_iter
+pub(crate) fn std_hash<E: Hash + ?Sized>(token: &E) -> u64 {
+    let mut hasher = StdHasher(StableHasher::new());
+    token.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Document frequencies of tokens in a collection of texts.
+///
+/// Each document is a sequence of tokens. A token is counted only once per document,
+/// no matter how many times it occurs in it.
+///
+/// The string representation (see [`Display`] and [`FromStr`]) is the number of documents
+/// and the total number of tokens in them on the first line followed by a line for each
+/// unique token with its hash and document frequency.
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Corpus {
+    docs: usize,
+    tokens: usize,
+    df: HashMap<u64, usize>,
+}
+
+impl Corpus {
+    /// Make an empty corpus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a document represented as a sequence of tokens.
+    pub fn add<I, E>(&mut self, doc: I)
+    where
+        I: IntoIterator<Item = E>,
+        E: StableHash,
+    {
+        let mut len = 0;
+        let unique: HashSet<u64> = doc
+            .into_iter()
+            .inspect(|_| len += 1)
+            .map(|t| stable_hash(&t))
+            .collect();
+        for hash in unique {
+            *self.df.entry(hash).or_insert(0) += 1;
+        }
+        self.docs += 1;
+        self.tokens += len;
+    }
+
+    /// Add a text as a document of whitespace-separated words.
+    ///
+    /// Use it for the corpus that will be used with [`Algorithm::for_words`].
+    ///
+    /// [`Algorithm::for_words`]: crate::Algorithm::for_words
+    pub fn add_words(&mut self, text: &str) {
+        self.add(text.split_whitespace());
+    }
+
+    /// Add all documents from another corpus.
+    pub fn merge(&mut self, other: &Corpus) {
+        for (hash, df) in &other.df {
+            *self.df.entry(*hash).or_insert(0) += df;
+        }
+        self.docs += other.docs;
+        self.tokens += other.tokens;
+    }
+
+    /// How many documents there are in the corpus.
+    pub fn docs(&self) -> usize {
+        self.docs
+    }
+
+    /// How many tokens there are in all documents in total.
+    pub fn tokens(&self) -> usize {
+        self.tokens
+    }
+
+    /// The average number of tokens in a document. Zero for an empty corpus.
+    pub fn avg_len(&self) -> f64 {
+        if self.docs == 0 {
+            0.
+        } else {
+            self.tokens as f64 / self.docs as f64
+        }
+    }
+
+    /// How many documents contain the given token.
+    pub fn df<E: StableHash + ?Sized>(&self, token: &E) -> usize {
+        self.df_of(stable_hash(token))
+    }
+
+    /// How many documents contain the token with the given hash.
+    pub(crate) fn df_of(&self, hash: u64) -> usize {
+        self.df.get(&hash).copied().unwrap_or(0)
+    }
+
+    /// Smoothed [inverse document frequency] of the token: `ln((1 + N) / (1 + df)) + 1`.
+    ///
+    /// The smoothing makes the weight positive for all tokens, including the ones
+    /// that occur in every document and the ones not seen in the corpus at all.
+    /// For an empty corpus, all tokens have the weight of 1.
+    ///
+    /// [inverse document frequency]: https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+    pub fn idf<E: StableHash + ?Sized>(&self, token: &E) -> f64 {
+        self.idf_of(stable_hash(token))
+    }
+
+    /// Smoothed inverse document frequency of the token with the given hash.
+    pub(crate) fn idf_of(&self, hash: u64) -> f64 {
+        let n = self.docs as f64 + 1.;
+        let df = self.df_of(hash) as f64 + 1.;
+        (n / df).ln() + 1.
+    }
+
+    /// TF-IDF weights of unique tokens in the sequence, normalized to the unit length.
+    /// The tokens are looked up in the corpus by the given hash function.
+    pub(crate) fn weights<C, E, H>(&self, s: C, hash: H) -> HashMap<E, f64>
+    where
+        C: Iterator<Item = E>,
+        E: Eq + Hash,
+        H: Fn(&E) -> u64,
+    {
+        let mut weights: HashMap<E, f64> = HashMap::new();
+        for token in s {
+            *weights.entry(token).or_insert(0.) += 1.;
+        }
+        let mut norm = 0.;
+        for (token, weight) in &mut weights {
+            *weight *= self.idf_of(hash(token));
+            norm += *weight * *weight;
+        }
+        let norm = f64::sqrt(norm);
+        for weight in weights.values_mut() {
+            *weight /= norm;
+        }
+        weights
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Corpus {
+    /// Build a corpus from texts, each text is a document of whitespace-separated words.
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut corpus = Corpus::new();
+        corpus.extend(iter);
+        corpus
+    }
+}
+
+impl<'a> Extend<&'a str> for Corpus {
+    /// Add texts, each text is a document of whitespace-separated words.
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for text in iter {
+            self.add_words(text);
+        }
+    }
+}
+
+impl fmt::Display for Corpus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {}", self.docs, self.tokens)?;
+        let mut df: Vec<_> = self.df.iter().collect();
+        df.sort_unstable();
+        for (hash, df) in df {
+            writeln!(f, "{hash:016x} {df}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Corpus {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let header = lines.next().unwrap_or_default();
+        let (docs, tokens) = header.split_once(' ').unwrap_or((header, ""));
+        let mut df = HashMap::new();
+        for line in lines {
+            let (hash, count) = line.split_once(' ').unwrap_or((line, ""));
+            df.insert(u64::from_str_radix(hash, 16)?, count.parse()?);
+        }
+        Ok(Self {
+            docs: docs.parse()?,
+            tokens: tokens.parse()?,
+            df,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use super::*;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn is_close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1E-5
+    }
+
+    fn corpus() -> Corpus {
+        ["acme inc", "globex inc inc", "initech inc", "umbrella ltd"]
+            .into_iter()
+            .collect()
+    }
+
+    #[rstest]
+    #[case("inc", 3)]
+    #[case("ltd", 1)]
+    #[case("acme", 1)]
+    #[case("hooli", 0)]
+    fn df(#[case] token: &str, #[case] exp: usize) {
+        assert!(corpus().df(token) == exp);
+    }
+
+    #[rstest]
+    #[case("inc", 1.223_144)]
+    #[case("ltd", 1.916_291)]
+    #[case("hooli", 2.609_438)]
+    fn idf(#[case] token: &str, #[case] exp: f64) {
+        let act = corpus().idf(token);
+        assert!(is_close(act, exp), "idf({}) is {}, not {}", token, act, exp);
+    }
+
+    #[test]
+    fn tokens() {
+        let c = corpus();
+        assert!(c.tokens() == 9);
+        assert!(c.avg_len() == 2.25);
+    }
+
+    #[test]
+    fn empty() {
+        let c = Corpus::new();
+        assert!(c.docs() == 0);
+        assert!(c.avg_len() == 0.);
+        assert!(c.idf("a") == 1.);
+    }
+
+    #[test]
+    fn token_types() {
+        let mut c = Corpus::new();
+        c.add("abca".chars());
+        c.add([1, 2, 3]);
+        assert!(c.docs() == 2);
+        assert!(c.tokens() == 7);
+        assert!(c.df(&'a') == 1);
+        assert!(c.df(&2) == 1);
+        assert!(c.df("a") == 0);
+    }
+
+    #[test]
+    fn incremental() {
+        let mut c = Corpus::new();
+        c.add_words("acme inc");
+        c.extend(["globex inc inc", "initech inc"]);
+        let mut other = Corpus::new();
+        other.add_words("umbrella ltd");
+        c.merge(&other);
+        assert!(c == corpus());
+    }
+
+    #[test]
+    fn weights() {
+        let w = Corpus::new().weights("aab".chars(), stable_hash);
+        assert!(is_close(w[&'a'], 2. / 5f64.sqrt()));
+        assert!(is_close(w[&'b'], 1. / 5f64.sqrt()));
+        assert!(Corpus::new().weights("".chars(), stable_hash).is_empty());
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        assert!(stable_hash("") == 0xaf64_724c_8602_eb6e);
+        assert!(stable_hash(&'a') == 0xac80_4b82_0e4f_e984);
+        assert!(stable_hash(&1u64) == 0x89cd_3129_1d2a_efa4);
+        assert!(stable_hash("a") != stable_hash(&'a'));
+        assert!(stable_hash(&("ab", "c")) != stable_hash(&("a", "bc")));
+    }
+
+    #[test]
+    fn hash_owned() {
+        assert!(stable_hash(&String::from("ab")) == stable_hash("ab"));
+        assert!(stable_hash(&vec![1u8, 2]) == stable_hash([1u8, 2].as_slice()));
+        assert!(stable_hash(&[1u8, 2].as_slice()) != stable_hash(&[1u8, 2, 0].as_slice()));
+    }
+
+    #[test]
+    fn to_string() {
+        let mut c = Corpus::new();
+        c.add_words("a b");
+        c.add_words("b");
+        let s = c.to_string();
+        let lines: Vec<_> = s.lines().collect();
+        assert!(lines.len() == 3);
+        assert!(lines[0] == "2 3");
+        let exp = format!("{:016x} 2", stable_hash("b"));
+        assert!(lines.contains(&exp.as_str()));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("x")]
+    #[case("1")]
+    #[case("1 x")]
+    #[case("1 2\nzz 1")]
+    #[case("1 2\n0000000000000001")]
+    #[case("1 2\n0000000000000001 x")]
+    fn parse_error(#[case] s: &str) {
+        assert!(s.parse::<Corpus>().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(texts: Vec<String>) {
+            let c: Corpus = texts.iter().map(String::as_str).collect();
+            let loaded: Corpus = c.to_string().parse().unwrap();
+            prop_assert!(loaded == c);
+        }
+
+        #[test]
+        fn prop_std_hash(s: String, c: char, n: usize, i: i16, w: u128) {
+            prop_assert!(std_hash(s.as_str()) == stable_hash(s.as_str()));
+            prop_assert!(std_hash(&(c, c)) == stable_hash(&(c, c)));
+            prop_assert!(std_hash(&n) == stable_hash(&n));
+            prop_assert!(std_hash(&(i, w)) == stable_hash(&(i, w)));
+        }
+    }
+}
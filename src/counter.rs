@@ -28,6 +28,22 @@ where
         counter
     }
 
+    /// Create a counter from a sequence.
+    ///
+    /// If `as_set` is true, each unique item is counted only once.
+    pub fn from_iter_mode<I>(iter: I, as_set: bool) -> Counter<K>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut counter = Counter::from_iter(iter);
+        if as_set {
+            for count in counter.map.values_mut() {
+                *count = 1;
+            }
+        }
+        counter
+    }
+
     /// Merge items from a sequence into the Counter
     pub fn update<I>(&mut self, iter: I)
     where
@@ -100,6 +116,20 @@ where
         result
     }
 
+    /// Counts of each item from both multisets, including items missing in one of them.
+    pub fn pairs<'a>(&'a self, rhs: &'a Counter<K>) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let lhs_pairs = self
+            .map
+            .iter()
+            .map(|(key, lhs_count)| (*lhs_count, *rhs.map.get(key).unwrap_or(&0)));
+        let rhs_pairs = rhs
+            .map
+            .iter()
+            .filter(|(key, _)| !self.map.contains_key(key))
+            .map(|(_, rhs_count)| (0, *rhs_count));
+        lhs_pairs.chain(rhs_pairs)
+    }
+
     /// How many there are item in left that aren't in the right
     pub fn diff_count(&self, rhs: &Counter<K>) -> usize {
         let mut result = 0;
@@ -150,5 +180,17 @@ mod tests {
         // assert!(eq(c1.intersect(&c2), &Counter::from_iter(3..=5)));
         assert!(c1.intersect_count(&c2) == 3);
         assert!(c1.union_count(&c2) == 7);
+        let mut pairs: Vec<_> = c1.pairs(&c2).collect();
+        pairs.sort_unstable();
+        assert!(pairs == [(0, 1), (0, 1), (1, 0), (1, 0), (1, 1), (1, 1), (1, 1)]);
+    }
+
+    #[rstest]
+    #[case(false, 4)]
+    #[case(true, 2)]
+    fn from_iter_mode(#[case] as_set: bool, #[case] exp: usize) {
+        let c = Counter::from_iter_mode("aaab".chars(), as_set);
+        assert!(c.count() == exp);
+        assert!(c.get(&'b') == Some(&1));
     }
 }
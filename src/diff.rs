@@ -0,0 +1,237 @@
+//! Differences between two sequences.
+//!
+//! A difference is a list of [`Hunk`]s. Each hunk says what to do with a range
+//! of elements of the first sequence to turn it into the corresponding range of
+//! the second one, like opcodes in Python's [difflib]. Together, the hunks cover
+//! both sequences completely and in order.
+//!
+//!     use textdistance::LCSSeq;
+//!     use textdistance::diff::{Hunk, Tag};
+//!
+//!     let s1: Vec<char> = "kitten".chars().collect();
+//!     let s2: Vec<char> = "sitting".chars().collect();
+//!     let hunks = LCSSeq::default().diff(&s1, &s2);
+//!     assert!(hunks[0] == Hunk { tag: Tag::Replace, range1: 0..1, range2: 0..1 });
+//!     assert!(hunks[1] == Hunk { tag: Tag::Equal, range1: 1..4, range2: 1..4 });
+//!
+//! [difflib]: https://docs.python.org/3/library/difflib.html#difflib.SequenceMatcher.get_opcodes
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// What to do with the elements of a [`Hunk`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tag {
+    /// The ranges of both sequences are equal.
+    Equal,
+
+    /// The range of the first sequence should be replaced by the range of the second one.
+    Replace,
+
+    /// The range of the first sequence should be deleted. The range of the second one is empty.
+    Delete,
+
+    /// The range of the second sequence should be inserted. The range of the first one is empty.
+    Insert,
+}
+
+/// A part of the difference between two sequences.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Hunk {
+    /// What to do with the elements.
+    pub tag: Tag,
+
+    /// The range of elements in the first sequence.
+    pub range1: Range<usize>,
+
+    /// The range of elements in the second sequence.
+    pub range2: Range<usize>,
+}
+
+/// Split hunks into groups of changes with up to `context` equal elements around them,
+/// like `get_grouped_opcodes` in Python's difflib. Each group is a hunk of a unified diff.
+///
+/// Long equal ranges between changes are cut so that each group starts and ends
+/// with at most `context` equal elements. Groups without changes are dropped.
+///
+///     use textdistance::LCSSeq;
+///     use textdistance::diff::{group, Tag};
+///
+///     let s1: Vec<char> = "a1bcdefghij2k".chars().collect();
+///     let s2: Vec<char> = "a0bcdefghij3k".chars().collect();
+///     let groups = group(LCSSeq::default().diff(&s1, &s2), 2);
+///     assert!(groups.len() == 2);
+///     assert!(groups[0][0].range1 == (0..1));
+///     assert!(groups[0][2].range1 == (2..4));
+///     assert!(groups[1][0].range1 == (9..11));
+///
+pub fn group(mut hunks: Vec<Hunk>, context: usize) -> Vec<Vec<Hunk>> {
+    if let Some(first) = hunks.first_mut() {
+        if first.tag == Tag::Equal {
+            first.range1.start = first
+                .range1
+                .start
+                .max(first.range1.end.saturating_sub(context));
+            first.range2.start = first
+                .range2
+                .start
+                .max(first.range2.end.saturating_sub(context));
+        }
+    }
+    if let Some(last) = hunks.last_mut() {
+        if last.tag == Tag::Equal {
+            last.range1.end = last.range1.end.min(last.range1.start + context);
+            last.range2.end = last.range2.end.min(last.range2.start + context);
+        }
+    }
+
+    let mut groups: Vec<Vec<Hunk>> = Vec::new();
+    let mut current: Vec<Hunk> = Vec::new();
+    for mut hunk in hunks {
+        // end the current group and start a new one on a long range with no changes
+        if hunk.tag == Tag::Equal && hunk.range1.len() > context * 2 {
+            current.push(Hunk {
+                tag: Tag::Equal,
+                range1: hunk.range1.start..hunk.range1.end.min(hunk.range1.start + context),
+                range2: hunk.range2.start..hunk.range2.end.min(hunk.range2.start + context),
+            });
+            groups.push(core::mem::take(&mut current));
+            hunk.range1.start = hunk.range1.start.max(hunk.range1.end - context);
+            hunk.range2.start = hunk.range2.start.max(hunk.range2.end - context);
+        }
+        current.push(hunk);
+    }
+    groups.push(current);
+    groups.retain(|group| group.iter().any(|hunk| hunk.tag != Tag::Equal));
+    groups
+}
+
+/// Build hunks from matching blocks.
+///
+/// Each block is a triple `(i, j, len)` meaning that `s1[i..i + len] == s2[j..j + len]`.
+/// The blocks must be sorted and must not overlap. Adjacent blocks are merged
+/// into a single [`Tag::Equal`] hunk, and the gaps between them become
+/// [`Tag::Replace`], [`Tag::Delete`], or [`Tag::Insert`] hunks.
+pub(crate) fn from_blocks<I>(blocks: I, len1: usize, len2: usize) -> Vec<Hunk>
+where
+    I: IntoIterator<Item = (usize, usize, usize)>,
+{
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+    let blocks = blocks.into_iter().chain([(len1, len2, 0)]);
+    for (bi, bj, len) in blocks {
+        let tag = match (i < bi, j < bj) {
+            (true, true) => Some(Tag::Replace),
+            (true, false) => Some(Tag::Delete),
+            (false, true) => Some(Tag::Insert),
+            (false, false) => None,
+        };
+        if let Some(tag) = tag {
+            hunks.push(Hunk {
+                tag,
+                range1: i..bi,
+                range2: j..bj,
+            });
+        }
+        i = bi + len;
+        j = bj + len;
+        if len == 0 {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some(last) if last.tag == Tag::Equal => {
+                last.range1.end = i;
+                last.range2.end = j;
+            }
+            _ => hunks.push(Hunk {
+                tag: Tag::Equal,
+                range1: bi..i,
+                range2: bj..j,
+            }),
+        }
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    fn hunk(tag: Tag, range1: Range<usize>, range2: Range<usize>) -> Hunk {
+        Hunk {
+            tag,
+            range1,
+            range2,
+        }
+    }
+
+    #[rstest]
+    #[case("", "", 3, vec![])]
+    #[case("abc", "abc", 3, vec![])]
+    #[case("abc", "abd", 3, vec![vec![
+        hunk(Tag::Equal, 0..2, 0..2),
+        hunk(Tag::Replace, 2..3, 2..3),
+    ]])]
+    #[case("abcdefgh", "xbcdefgh", 1, vec![vec![
+        hunk(Tag::Replace, 0..1, 0..1),
+        hunk(Tag::Equal, 1..2, 1..2),
+    ]])]
+    #[case("abcdefgh", "xbcdefgy", 1, vec![
+        vec![hunk(Tag::Replace, 0..1, 0..1), hunk(Tag::Equal, 1..2, 1..2)],
+        vec![hunk(Tag::Equal, 6..7, 6..7), hunk(Tag::Replace, 7..8, 7..8)],
+    ])]
+    #[case("abcdefgh", "xbcdefgy", 3, vec![vec![
+        hunk(Tag::Replace, 0..1, 0..1),
+        hunk(Tag::Equal, 1..7, 1..7),
+        hunk(Tag::Replace, 7..8, 7..8),
+    ]])]
+    fn groups(
+        #[case] s1: &str,
+        #[case] s2: &str,
+        #[case] context: usize,
+        #[case] exp: Vec<Vec<Hunk>>,
+    ) {
+        let s1: Vec<char> = s1.chars().collect();
+        let s2: Vec<char> = s2.chars().collect();
+        let hunks = crate::LCSSeq::default().diff(&s1, &s2);
+        assert!(group(hunks, context) == exp);
+    }
+
+    #[rstest]
+    #[case(vec![], 0, 0, vec![])]
+    #[case(vec![], 2, 0, vec![hunk(Tag::Delete, 0..2, 0..0)])]
+    #[case(vec![], 0, 3, vec![hunk(Tag::Insert, 0..0, 0..3)])]
+    #[case(vec![], 2, 3, vec![hunk(Tag::Replace, 0..2, 0..3)])]
+    #[case(vec![(0, 0, 2)], 2, 2, vec![hunk(Tag::Equal, 0..2, 0..2)])]
+    #[case(vec![(0, 0, 1), (1, 1, 1)], 2, 2, vec![hunk(Tag::Equal, 0..2, 0..2)])]
+    #[case(
+        vec![(1, 0, 2)],
+        4,
+        3,
+        vec![
+            hunk(Tag::Delete, 0..1, 0..0),
+            hunk(Tag::Equal, 1..3, 0..2),
+            hunk(Tag::Replace, 3..4, 2..3),
+        ],
+    )]
+    #[case(
+        vec![(0, 0, 1), (1, 2, 1)],
+        2,
+        3,
+        vec![
+            hunk(Tag::Equal, 0..1, 0..1),
+            hunk(Tag::Insert, 1..1, 1..2),
+            hunk(Tag::Equal, 1..2, 2..3),
+        ],
+    )]
+    fn matching_blocks(
+        #[case] blocks: Vec<(usize, usize, usize)>,
+        #[case] len1: usize,
+        #[case] len2: usize,
+        #[case] exp: Vec<Hunk>,
+    ) {
+        assert!(from_blocks(blocks, len1, len2) == exp);
+    }
+}
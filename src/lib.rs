@@ -16,74 +16,162 @@ extern crate alloc;
 mod algorithm;
 mod counter;
 mod result;
+mod scratch;
 
+pub mod automaton;
+pub mod compression;
+pub mod corpus;
+pub mod diff;
 pub mod nstr;
+pub mod phonetic;
+pub mod render;
+pub mod search;
+pub mod spelling;
 pub mod str;
 
 mod algorithms {
+    pub mod arith_ncd;
     pub mod bag;
+    pub mod bhattacharyya;
+    pub mod bm25;
+    pub mod braun_blanquet;
+    pub mod bray_curtis;
+    pub mod bwtrle_ncd;
+    pub mod canberra;
+    pub mod chebyshev;
     pub mod cosine;
     pub mod damerau_levenshtein;
+    pub mod editex;
     pub mod entropy_ncd;
+    pub mod euclidean;
+    pub mod eudex;
     pub mod hamming;
+    pub mod hellinger;
     pub mod jaccard;
     pub mod jaro;
     pub mod jaro_winkler;
+    pub mod jensen_shannon;
+    pub mod kulczynski;
+    pub mod kullback_leibler;
     pub mod lcsseq;
     pub mod lcsstr;
     pub mod length;
     pub mod levenshtein;
     pub mod lig3;
+    pub mod lz77_ncd;
+    pub mod manhattan;
+    pub mod minkowski;
     pub mod mlipns;
+    pub mod mra;
+    pub mod ochiai;
     pub mod overlap;
     pub mod prefix;
+    pub mod qgram;
     pub mod ratcliff_obershelp;
     pub mod roberts;
+    pub mod rogers_tanimoto;
     pub mod sift4_common;
     pub mod sift4_simple;
+    pub mod simpson;
     pub mod smith_waterman;
+    pub mod soft_tfidf;
+    pub mod sokal_michener;
     pub mod sorensen_dice;
+    pub mod strcmp95;
     pub mod suffix;
+    pub mod tfidf;
     pub mod tversky;
     pub mod yujian_bo;
 }
 
 pub use self::algorithm::Algorithm;
 #[cfg(feature = "std")]
+pub use self::algorithms::arith_ncd::ArithNCD;
+#[cfg(feature = "std")]
 pub use self::algorithms::bag::Bag;
 #[cfg(feature = "std")]
+pub use self::algorithms::bhattacharyya::Bhattacharyya;
+#[cfg(feature = "std")]
+pub use self::algorithms::bm25::BM25;
+#[cfg(feature = "std")]
+pub use self::algorithms::braun_blanquet::BraunBlanquet;
+#[cfg(feature = "std")]
+pub use self::algorithms::bray_curtis::BrayCurtis;
+#[cfg(feature = "std")]
+pub use self::algorithms::bwtrle_ncd::BWTRLENCD;
+#[cfg(feature = "std")]
+pub use self::algorithms::canberra::Canberra;
+#[cfg(feature = "std")]
+pub use self::algorithms::chebyshev::Chebyshev;
+#[cfg(feature = "std")]
 pub use self::algorithms::cosine::Cosine;
 #[cfg(feature = "std")]
 pub use self::algorithms::damerau_levenshtein::DamerauLevenshtein;
+pub use self::algorithms::editex::Editex;
 #[cfg(feature = "std")]
 pub use self::algorithms::entropy_ncd::EntropyNCD;
+#[cfg(feature = "std")]
+pub use self::algorithms::euclidean::Euclidean;
+pub use self::algorithms::eudex::Eudex;
 pub use self::algorithms::hamming::Hamming;
 #[cfg(feature = "std")]
+pub use self::algorithms::hellinger::Hellinger;
+#[cfg(feature = "std")]
 pub use self::algorithms::jaccard::Jaccard;
 pub use self::algorithms::jaro::Jaro;
+pub use self::algorithms::jaro::JaroWindow;
 pub use self::algorithms::jaro_winkler::JaroWinkler;
+#[cfg(feature = "std")]
+pub use self::algorithms::jensen_shannon::JensenShannon;
+#[cfg(feature = "std")]
+pub use self::algorithms::kulczynski::Kulczynski;
+#[cfg(feature = "std")]
+pub use self::algorithms::kullback_leibler::KullbackLeibler;
 pub use self::algorithms::lcsseq::LCSSeq;
 pub use self::algorithms::lcsstr::LCSStr;
 pub use self::algorithms::length::Length;
 pub use self::algorithms::levenshtein::Levenshtein;
 pub use self::algorithms::lig3::LIG3;
+#[cfg(feature = "std")]
+pub use self::algorithms::lz77_ncd::LZ77NCD;
+#[cfg(feature = "std")]
+pub use self::algorithms::manhattan::Manhattan;
+#[cfg(feature = "std")]
+pub use self::algorithms::minkowski::Minkowski;
 pub use self::algorithms::mlipns::MLIPNS;
+pub use self::algorithms::mra::MRA;
+#[cfg(feature = "std")]
+pub use self::algorithms::ochiai::Ochiai;
 #[cfg(feature = "std")]
 pub use self::algorithms::overlap::Overlap;
 pub use self::algorithms::prefix::Prefix;
+#[cfg(feature = "std")]
+pub use self::algorithms::qgram::QGram;
 pub use self::algorithms::ratcliff_obershelp::RatcliffObershelp;
 #[cfg(feature = "std")]
 pub use self::algorithms::roberts::Roberts;
+#[cfg(feature = "std")]
+pub use self::algorithms::rogers_tanimoto::RogersTanimoto;
 pub use self::algorithms::sift4_common::Sift4Common;
 pub use self::algorithms::sift4_simple::Sift4Simple;
+#[cfg(feature = "std")]
+pub use self::algorithms::simpson::Simpson;
 pub use self::algorithms::smith_waterman::SmithWaterman;
 #[cfg(feature = "std")]
+pub use self::algorithms::soft_tfidf::SoftTfIdf;
+#[cfg(feature = "std")]
+pub use self::algorithms::sokal_michener::SokalMichener;
+#[cfg(feature = "std")]
 pub use self::algorithms::sorensen_dice::SorensenDice;
+pub use self::algorithms::strcmp95::Strcmp95;
 pub use self::algorithms::suffix::Suffix;
 #[cfg(feature = "std")]
+pub use self::algorithms::tfidf::TfIdf;
+#[cfg(feature = "std")]
 pub use self::algorithms::tversky::Tversky;
 pub use self::algorithms::yujian_bo::YujianBo;
 pub use self::result::Result;
+pub use self::scratch::{Scratch, WithScratch};
 
 #[cfg(test)]
 mod tests {
@@ -113,6 +201,10 @@ mod tests {
             12 => Bag::default().for_str(s1, s2),
             13 => SmithWaterman::default().for_str(s1, s2),
             14 => Sift4Common::default().for_str(s1, s2),
+            15 => Editex::default().for_str(s1, s2),
+            16 => MRA::default().for_str(s1, s2),
+            17 => Eudex::default().for_str(s1, s2),
+            18 => QGram::default().for_str(s1, s2),
             _ => panic!("there are not so many algorithms!"),
         }
     }
@@ -130,6 +222,29 @@ mod tests {
             9 => EntropyNCD::default().for_str(s1, s2),
             10 => LIG3::default().for_str(s1, s2),
             11 => Roberts::default().for_str(s1, s2),
+            12 => ArithNCD::default().for_str(s1, s2),
+            13 => BWTRLENCD::default().for_str(s1, s2),
+            14 => LZ77NCD::default().for_str(s1, s2),
+            15 => Manhattan::default().for_str(s1, s2),
+            16 => Euclidean::default().for_str(s1, s2),
+            17 => Chebyshev::default().for_str(s1, s2),
+            18 => Minkowski::default().for_str(s1, s2),
+            19 => JensenShannon::default().for_str(s1, s2),
+            20 => KullbackLeibler::default().for_str(s1, s2),
+            21 => Hellinger::default().for_str(s1, s2),
+            22 => Bhattacharyya::default().for_str(s1, s2),
+            23 => Ochiai::default().for_str(s1, s2),
+            24 => Kulczynski::default().for_str(s1, s2),
+            25 => Simpson::default().for_str(s1, s2),
+            26 => BraunBlanquet::default().for_str(s1, s2),
+            27 => BrayCurtis::default().for_str(s1, s2),
+            28 => Canberra::default().for_str(s1, s2),
+            29 => SokalMichener::default().for_str(s1, s2),
+            30 => RogersTanimoto::default().for_str(s1, s2),
+            31 => TfIdf::default().for_str(s1, s2),
+            32 => SoftTfIdf::default().for_str(s1, s2),
+            33 => BM25::default().for_str(s1, s2),
+            34 => Strcmp95::default().for_str(s1, s2),
             _ => panic!("there are not so many algorithms!"),
         }
     }
@@ -149,6 +264,10 @@ mod tests {
     #[case::bag(12)]
     #[case::smith_waterman(13)]
     #[case::sift4_common(14)]
+    #[case::editex(15)]
+    #[case::mra(16)]
+    #[case::eudex(17)]
+    #[case::qgram(18)]
     fn basic_usize(#[case] alg: usize) {
         let empty_res = get_result(alg, "", "");
         assert!(empty_res.dist() == 0);
@@ -156,7 +275,10 @@ mod tests {
             assert!(get_result(alg, "ab", "cde").dist() > 0);
             assert!(get_result(alg, "ab", "cde").ndist() > 0.);
         }
-        if alg != 11 {
+        // Editex: "a" and "e" are from the same phonetic group
+        // MRA: the rating is out of 6 even for short codices
+        // Eudex: hashes of different words may have the same bits
+        if alg != 11 && alg < 15 {
             assert!(get_result(alg, "spam", "qwer").sim() == 0);
             assert!(get_result(alg, "spam", "qwer").nsim() == 0.);
         }
@@ -176,10 +298,34 @@ mod tests {
     #[case::entropy_ncd(9)]
     #[case::lig3(10)]
     #[case::roberts(11)]
+    #[case::arith_ncd(12)]
+    #[case::bwtrle_ncd(13)]
+    #[case::lz77_ncd(14)]
+    #[case::manhattan(15)]
+    #[case::euclidean(16)]
+    #[case::chebyshev(17)]
+    #[case::minkowski(18)]
+    #[case::jensen_shannon(19)]
+    #[case::kullback_leibler(20)]
+    #[case::hellinger(21)]
+    #[case::bhattacharyya(22)]
+    #[case::ochiai(23)]
+    #[case::kulczynski(24)]
+    #[case::simpson(25)]
+    #[case::braun_blanquet(26)]
+    #[case::bray_curtis(27)]
+    #[case::canberra(28)]
+    #[case::sokal_michener(29)]
+    #[case::rogers_tanimoto(30)]
+    #[case::tfidf(31)]
+    #[case::soft_tfidf(32)]
+    #[case::bm25(33)]
+    #[case::strcmp95(34)]
     fn basic_f64(#[case] alg: usize) {
         let empty_res = get_result_f64(alg, "", "");
         assert!(get_result_f64(alg, "ab", "cde").ndist() > 0.);
-        if alg != 3 && alg != 9 {
+        // KullbackLeibler: smoothing makes distributions overlap
+        if alg != 3 && alg != 9 && alg != 20 {
             assert!(get_result_f64(alg, "spam", "qwer").nsim() == 0.);
         }
         assert!(empty_res.ndist() == 0.);
@@ -14,6 +14,8 @@ fn main() {
         "damerau_levenshtein_restricted" => {
             textdistance::str::damerau_levenshtein_restricted(s1, s2) as f64
         }
+        "editex" => textdistance::str::editex(s1, s2) as f64,
+        "eudex" => textdistance::str::eudex(s1, s2) as f64,
         "hamming" => textdistance::str::hamming(s1, s2) as f64,
         "lcsseq" => textdistance::str::lcsseq(s1, s2) as f64,
         "lcsstr" => textdistance::str::lcsstr(s1, s2) as f64,
@@ -24,6 +26,7 @@ fn main() {
         "jaro" => textdistance::str::jaro(s1, s2),
         "jaro_winkler" => textdistance::str::jaro_winkler(s1, s2),
         "yujian_bo" => textdistance::str::yujian_bo(s1, s2),
+        "mra" => textdistance::str::mra(s1, s2) as f64,
         "mlipns" => textdistance::str::mlipns(s1, s2) as f64,
         #[cfg(feature = "std")]
         "bag" => textdistance::str::bag(s1, s2) as f64,
@@ -45,7 +48,54 @@ fn main() {
         #[cfg(feature = "std")]
         "entropy_ncd" => textdistance::str::entropy_ncd(s1, s2),
         #[cfg(feature = "std")]
+        "arith_ncd" => textdistance::str::arith_ncd(s1, s2),
+        #[cfg(feature = "std")]
+        "bwtrle_ncd" => textdistance::str::bwtrle_ncd(s1, s2),
+        #[cfg(feature = "std")]
+        "lz77_ncd" => textdistance::str::lz77_ncd(s1, s2),
+        #[cfg(feature = "std")]
         "roberts" => textdistance::str::roberts(s1, s2),
+        #[cfg(feature = "std")]
+        "qgram" => textdistance::str::qgram(s1, s2) as f64,
+        #[cfg(feature = "std")]
+        "manhattan" => textdistance::str::manhattan(s1, s2),
+        #[cfg(feature = "std")]
+        "euclidean" => textdistance::str::euclidean(s1, s2),
+        #[cfg(feature = "std")]
+        "chebyshev" => textdistance::str::chebyshev(s1, s2),
+        #[cfg(feature = "std")]
+        "minkowski" => textdistance::str::minkowski(s1, s2),
+        #[cfg(feature = "std")]
+        "jensen_shannon" => textdistance::str::jensen_shannon(s1, s2),
+        #[cfg(feature = "std")]
+        "kullback_leibler" => textdistance::str::kullback_leibler(s1, s2),
+        #[cfg(feature = "std")]
+        "hellinger" => textdistance::str::hellinger(s1, s2),
+        #[cfg(feature = "std")]
+        "bhattacharyya" => textdistance::str::bhattacharyya(s1, s2),
+        #[cfg(feature = "std")]
+        "ochiai" => textdistance::str::ochiai(s1, s2),
+        #[cfg(feature = "std")]
+        "kulczynski" => textdistance::str::kulczynski(s1, s2),
+        #[cfg(feature = "std")]
+        "simpson" => textdistance::str::simpson(s1, s2),
+        #[cfg(feature = "std")]
+        "braun_blanquet" => textdistance::str::braun_blanquet(s1, s2),
+        #[cfg(feature = "std")]
+        "bray_curtis" => textdistance::str::bray_curtis(s1, s2),
+        #[cfg(feature = "std")]
+        "canberra" => textdistance::str::canberra(s1, s2),
+        #[cfg(feature = "std")]
+        "sokal_michener" => textdistance::str::sokal_michener(s1, s2),
+        #[cfg(feature = "std")]
+        "rogers_tanimoto" => textdistance::str::rogers_tanimoto(s1, s2),
+        #[cfg(feature = "std")]
+        "tfidf" => textdistance::str::tfidf(s1, s2),
+        #[cfg(feature = "std")]
+        "soft_tfidf" => textdistance::str::soft_tfidf(s1, s2),
+        #[cfg(feature = "std")]
+        "bm25" => textdistance::str::bm25(s1, s2),
+        "strcmp95" => textdistance::str::strcmp95(s1, s2),
         _ => panic!("unknown algorithm name"),
     };
     println!("{res}");
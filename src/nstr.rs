@@ -33,6 +33,30 @@ pub fn damerau_levenshtein_restricted(s1: &str, s2: &str) -> f64 {
     a.for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Editex distance][1] for two strings.
+///
+/// A wrapper for [`Editex`].
+///
+///     use textdistance::nstr::editex;
+///     assert!(editex("cat", "hat") == 2./6.); // "c" and "h" are from different groups
+///
+/// [1]: https://anlp.org/wp-content/uploads/1996/01/Zobel-Dart-1996.pdf
+pub fn editex(s1: &str, s2: &str) -> f64 {
+    Editex::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Eudex][1] distance for two strings.
+///
+/// A wrapper for [`Eudex`].
+///
+///     use textdistance::nstr::eudex;
+///     assert!(eudex("jumpo", "jumbo") == 5./64.);
+///
+/// [1]: https://github.com/ticki/eudex
+pub fn eudex(s1: &str, s2: &str) -> f64 {
+    Eudex::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Hamming distance][1] for two strings.
 ///
 /// A wrapper for [`Hamming`].
@@ -93,6 +117,18 @@ pub fn ratcliff_obershelp(s1: &str, s2: &str) -> f64 {
     RatcliffObershelp::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Match Rating Approach][1] similarity for two strings.
+///
+/// A wrapper for [`MRA`].
+///
+///     use textdistance::nstr::mra;
+///     assert!(mra("Smith", "Smyth") == 5./6.); // SMTH and SMYTH
+///
+/// [1]: https://en.wikipedia.org/wiki/Match_rating_approach
+pub fn mra(s1: &str, s2: &str) -> f64 {
+    MRA::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Sift4 distance][1] for two strings using the "simplest" algorithm.
 ///
 /// A wrapper for [`Sift4Simple`].
@@ -141,6 +177,18 @@ pub fn jaro_winkler(s1: &str, s2: &str) -> f64 {
     JaroWinkler::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Strcmp95 similarity][1] for two strings.
+///
+/// A wrapper for [`Strcmp95`].
+///
+///     use textdistance::nstr::strcmp95;
+///     assert!(strcmp95("martha", "marhta") > 0.96);
+///
+/// [1]: https://web.archive.org/web/20100227020019/http://www.census.gov/geo/msb/stand/strcmp.c
+pub fn strcmp95(s1: &str, s2: &str) -> f64 {
+    Strcmp95::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Yujian-Bo normalization][1] of [Levenshtein] for two strings.
 ///
 /// A wrapper for [`YujianBo`].
@@ -178,6 +226,19 @@ pub fn bag(s1: &str, s2: &str) -> f64 {
     Bag::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Q-gram distance][1] for two strings.
+///
+/// A wrapper for [`QGram`].
+///
+///     use textdistance::nstr::qgram;
+///     assert!(qgram("abc", "acbd") == 7./9.);
+///
+/// [1]: https://doi.org/10.1016/0304-3975(92)90143-4
+#[cfg(feature = "std")]
+pub fn qgram(s1: &str, s2: &str) -> f64 {
+    QGram::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [LIG3 normalization][1] of [Hamming] by [Levenshtein] for two strings.
 ///
 /// A wrapper for [`LIG3`].
@@ -203,6 +264,19 @@ pub fn jaccard(s1: &str, s2: &str) -> f64 {
     Jaccard::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Jaccard similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Jaccard`] with `as_set` enabled.
+///
+///     use textdistance::nstr::jaccard_set;
+///     assert_eq!(jaccard_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Jaccard_index
+#[cfg(feature = "std")]
+pub fn jaccard_set(s1: &str, s2: &str) -> f64 {
+    Jaccard { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Sørensen–Dice normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`SorensenDice`].
@@ -216,6 +290,19 @@ pub fn sorensen_dice(s1: &str, s2: &str) -> f64 {
     SorensenDice::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Sørensen–Dice similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`SorensenDice`] with `as_set` enabled.
+///
+///     use textdistance::nstr::sorensen_dice_set;
+///     assert_eq!(sorensen_dice_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient
+#[cfg(feature = "std")]
+pub fn sorensen_dice_set(s1: &str, s2: &str) -> f64 {
+    SorensenDice { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Tversky normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Tversky`].
@@ -229,6 +316,23 @@ pub fn tversky(s1: &str, s2: &str) -> f64 {
     Tversky::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Tversky similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Tversky`] with `as_set` enabled.
+///
+///     use textdistance::nstr::tversky_set;
+///     assert_eq!(tversky_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Tversky_index
+#[cfg(feature = "std")]
+pub fn tversky_set(s1: &str, s2: &str) -> f64 {
+    let a = Tversky {
+        as_set: true,
+        ..Default::default()
+    };
+    a.for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Overlap normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Overlap`].
@@ -242,6 +346,19 @@ pub fn overlap(s1: &str, s2: &str) -> f64 {
     Overlap::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Overlap similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Overlap`] with `as_set` enabled.
+///
+///     use textdistance::nstr::overlap_set;
+///     assert_eq!(overlap_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Overlap_coefficient
+#[cfg(feature = "std")]
+pub fn overlap_set(s1: &str, s2: &str) -> f64 {
+    Overlap { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Cosine normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Cosine`].
@@ -255,6 +372,267 @@ pub fn cosine(s1: &str, s2: &str) -> f64 {
     Cosine::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [Cosine similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Cosine`] with `as_set` enabled.
+///
+///     use textdistance::nstr::cosine_set;
+///     assert_eq!(cosine_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Cosine_similarity
+#[cfg(feature = "std")]
+pub fn cosine_set(s1: &str, s2: &str) -> f64 {
+    Cosine { as_set: true }.for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Manhattan distance][1] for two strings.
+///
+/// A wrapper for [`Manhattan`].
+///
+///     use textdistance::nstr::manhattan;
+///     assert_eq!(manhattan("abc", "acbd"), 1. / 7.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Taxicab_geometry
+#[cfg(feature = "std")]
+pub fn manhattan(s1: &str, s2: &str) -> f64 {
+    Manhattan::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Euclidean distance][1] for two strings.
+///
+/// A wrapper for [`Euclidean`].
+///
+///     use textdistance::nstr::euclidean;
+///     assert_eq!(euclidean("abc", "acbd"), 0.3779644730092272);
+///
+/// [1]: https://en.wikipedia.org/wiki/Euclidean_distance
+#[cfg(feature = "std")]
+pub fn euclidean(s1: &str, s2: &str) -> f64 {
+    Euclidean::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Chebyshev distance][1] for two strings.
+///
+/// A wrapper for [`Chebyshev`].
+///
+///     use textdistance::nstr::chebyshev;
+///     assert_eq!(chebyshev("abc", "acbd"), 1.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Chebyshev_distance
+#[cfg(feature = "std")]
+pub fn chebyshev(s1: &str, s2: &str) -> f64 {
+    Chebyshev::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Minkowski distance][1] for two strings.
+///
+/// A wrapper for [`Minkowski`].
+///
+///     use textdistance::nstr::minkowski;
+///     assert_eq!(minkowski("abc", "acbd"), 0.3779644730092272);
+///
+/// [1]: https://en.wikipedia.org/wiki/Minkowski_distance
+#[cfg(feature = "std")]
+pub fn minkowski(s1: &str, s2: &str) -> f64 {
+    Minkowski::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Jensen-Shannon divergence][1] for two strings.
+///
+/// A wrapper for [`JensenShannon`].
+///
+///     use textdistance::nstr::jensen_shannon;
+///     assert_eq!(jensen_shannon("abc", "acbd"), 0.13792538097003013);
+///
+/// [1]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+#[cfg(feature = "std")]
+pub fn jensen_shannon(s1: &str, s2: &str) -> f64 {
+    JensenShannon::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized smoothed [Kullback-Leibler divergence][1] for two strings.
+///
+/// A wrapper for [`KullbackLeibler`].
+///
+///     use textdistance::nstr::kullback_leibler;
+///     assert_eq!(kullback_leibler("abc", "acbd"), 0.06989735497174632);
+///
+/// [1]: https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence
+#[cfg(feature = "std")]
+pub fn kullback_leibler(s1: &str, s2: &str) -> f64 {
+    KullbackLeibler::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Hellinger distance][1] for two strings.
+///
+/// A wrapper for [`Hellinger`].
+///
+///     use textdistance::nstr::hellinger;
+///     assert_eq!(hellinger("abc", "acbd"), 0.36602540378443854);
+///
+/// [1]: https://en.wikipedia.org/wiki/Hellinger_distance
+#[cfg(feature = "std")]
+pub fn hellinger(s1: &str, s2: &str) -> f64 {
+    Hellinger::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Bhattacharyya coefficient][1] for two strings.
+///
+/// A wrapper for [`Bhattacharyya`].
+///
+///     use textdistance::nstr::bhattacharyya;
+///     assert_eq!(bhattacharyya("abc", "acbd"), 0.8660254037844387);
+///
+/// [1]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+#[cfg(feature = "std")]
+pub fn bhattacharyya(s1: &str, s2: &str) -> f64 {
+    Bhattacharyya::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Ochiai coefficient][1] for two strings.
+///
+/// A wrapper for [`Ochiai`].
+///
+///     use textdistance::nstr::ochiai;
+///     assert_eq!(ochiai("abc", "acbd"), 0.8660254037844387);
+///
+/// [1]: https://en.wikipedia.org/wiki/Cosine_similarity#Otsuka%E2%80%93Ochiai_coefficient
+#[cfg(feature = "std")]
+pub fn ochiai(s1: &str, s2: &str) -> f64 {
+    Ochiai::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Kulczynski similarity][1] for two strings.
+///
+/// A wrapper for [`Kulczynski`].
+///
+///     use textdistance::nstr::kulczynski;
+///     assert_eq!(kulczynski("abc", "acbd"), 0.875);
+///
+/// [1]: https://doi.org/10.1002/9781118445112.stat07933
+#[cfg(feature = "std")]
+pub fn kulczynski(s1: &str, s2: &str) -> f64 {
+    Kulczynski::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Simpson similarity][1] for two strings.
+///
+/// A wrapper for [`Simpson`].
+///
+///     use textdistance::nstr::simpson;
+///     assert_eq!(simpson("abc", "acbd"), 1.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Overlap_coefficient
+#[cfg(feature = "std")]
+pub fn simpson(s1: &str, s2: &str) -> f64 {
+    Simpson::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Braun-Blanquet similarity][1] for two strings.
+///
+/// A wrapper for [`BraunBlanquet`].
+///
+///     use textdistance::nstr::braun_blanquet;
+///     assert_eq!(braun_blanquet("abc", "acbd"), 0.75);
+///
+/// [1]: https://doi.org/10.1002/9781118445112.stat07933
+#[cfg(feature = "std")]
+pub fn braun_blanquet(s1: &str, s2: &str) -> f64 {
+    BraunBlanquet::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Bray-Curtis dissimilarity][1] for two strings.
+///
+/// A wrapper for [`BrayCurtis`].
+///
+///     use textdistance::nstr::bray_curtis;
+///     assert_eq!(bray_curtis("abc", "acbd"), 0.14285714285714285);
+///
+/// [1]: https://en.wikipedia.org/wiki/Bray%E2%80%93Curtis_dissimilarity
+#[cfg(feature = "std")]
+pub fn bray_curtis(s1: &str, s2: &str) -> f64 {
+    BrayCurtis::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Canberra distance][1] for two strings.
+///
+/// A wrapper for [`Canberra`].
+///
+///     use textdistance::nstr::canberra;
+///     assert_eq!(canberra("abc", "acbd"), 0.25);
+///
+/// [1]: https://en.wikipedia.org/wiki/Canberra_distance
+#[cfg(feature = "std")]
+pub fn canberra(s1: &str, s2: &str) -> f64 {
+    Canberra::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Sokal-Michener similarity][1] for two strings.
+///
+/// A wrapper for [`SokalMichener`].
+///
+///     use textdistance::nstr::sokal_michener;
+///     assert_eq!(sokal_michener("abc", "acbd"), 0.75);
+///
+/// [1]: https://en.wikipedia.org/wiki/Simple_matching_coefficient
+#[cfg(feature = "std")]
+pub fn sokal_michener(s1: &str, s2: &str) -> f64 {
+    SokalMichener::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Rogers-Tanimoto similarity][1] for two strings.
+///
+/// A wrapper for [`RogersTanimoto`].
+///
+///     use textdistance::nstr::rogers_tanimoto;
+///     assert_eq!(rogers_tanimoto("abc", "acbd"), 0.6);
+///
+/// [1]: https://doi.org/10.1126/science.132.3434.1115
+#[cfg(feature = "std")]
+pub fn rogers_tanimoto(s1: &str, s2: &str) -> f64 {
+    RogersTanimoto::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [TF-IDF][1] weighted [Cosine similarity][2] for two strings.
+///
+/// A wrapper for [`TfIdf`].
+///
+///     use textdistance::nstr::tfidf;
+///     assert_eq!(tfidf("abc", "cba"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+/// [2]: https://en.wikipedia.org/wiki/Cosine_similarity
+#[cfg(feature = "std")]
+pub fn tfidf(s1: &str, s2: &str) -> f64 {
+    TfIdf::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Soft TF-IDF similarity][1] for two strings.
+///
+/// A wrapper for [`SoftTfIdf`].
+///
+///     use textdistance::nstr::soft_tfidf;
+///     assert_eq!(soft_tfidf("abc", "cba"), 1.0);
+///
+/// [1]: https://www.cs.cmu.edu/~wcohen/postscript/ijcai-ws-2003.pdf
+#[cfg(feature = "std")]
+pub fn soft_tfidf(s1: &str, s2: &str) -> f64 {
+    SoftTfIdf::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [Okapi BM25][1] relevance of the second string to the first one.
+///
+/// A wrapper for [`BM25`].
+///
+///     use textdistance::nstr::bm25;
+///     assert_eq!(bm25("ab", "abab"), 0.625);
+///
+/// [1]: https://en.wikipedia.org/wiki/Okapi_BM25
+#[cfg(feature = "std")]
+pub fn bm25(s1: &str, s2: &str) -> f64 {
+    BM25::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized common prefix length for two strings.
 ///
 /// A wrapper for [`Prefix`].
@@ -314,6 +692,48 @@ pub fn entropy_ncd(s1: &str, s2: &str) -> f64 {
     EntropyNCD::default().for_str(s1, s2).nval()
 }
 
+/// Calculate normalized [arithmetic coding]-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`ArithNCD`].
+///
+///     use textdistance::nstr::arith_ncd;
+///     assert_eq!(arith_ncd("abc", "acbd"), 0.5854815170379906);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [arithmetic coding]: https://en.wikipedia.org/wiki/Arithmetic_coding
+#[cfg(feature = "std")]
+pub fn arith_ncd(s1: &str, s2: &str) -> f64 {
+    ArithNCD::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [BWT]+RLE-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`BWTRLENCD`].
+///
+///     use textdistance::nstr::bwtrle_ncd;
+///     assert_eq!(bwtrle_ncd("abc", "acbd"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [BWT]: https://en.wikipedia.org/wiki/Burrows%E2%80%93Wheeler_transform
+#[cfg(feature = "std")]
+pub fn bwtrle_ncd(s1: &str, s2: &str) -> f64 {
+    BWTRLENCD::default().for_str(s1, s2).nval()
+}
+
+/// Calculate normalized [LZ77]-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`LZ77NCD`].
+///
+///     use textdistance::nstr::lz77_ncd;
+///     assert_eq!(lz77_ncd("abc", "acbd"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [LZ77]: https://en.wikipedia.org/wiki/LZ77_and_LZ78
+#[cfg(feature = "std")]
+pub fn lz77_ncd(s1: &str, s2: &str) -> f64 {
+    LZ77NCD::default().for_str(s1, s2).nval()
+}
+
 /// Calculate normalized [Roberts similarity] for two strings.
 ///
 /// A wrapper for [`Roberts`].
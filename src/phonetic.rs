@@ -0,0 +1,244 @@
+//! Phonetic encoders turning words into codes that represent how they sound.
+//!
+//! Each encoder implements the [`Encoder`] trait and can be used on its own:
+//!
+//!     use textdistance::phonetic::{Encoder, Soundex};
+//!     assert_eq!(Soundex::default().encode("Robert"), "R163");
+//!     assert_eq!(Soundex::default().encode("Rupert"), "R163");
+//!
+//! To compare texts by how they sound, wrap an encoder into [`Phonetic`]
+//! (the texts match if their codes are the same) or into [`PhoneticWith`]
+//! (an inner algorithm is applied to the codes):
+//!
+//!     use textdistance::{Algorithm, Levenshtein};
+//!     use textdistance::phonetic::{Metaphone, Phonetic, PhoneticWith};
+//!
+//!     let a = Phonetic::new(Metaphone::default());
+//!     assert!(a.for_str("Knight", "Night").val() == 1); // "NT"
+//!
+//!     let a = PhoneticWith::new(Metaphone::default(), Levenshtein::default());
+//!     assert!(a.for_str("Smith", "Schmidt").val() == 2); // "SM0" and "SKMT"
+//!
+use crate::{Algorithm, Result};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+mod caverphone;
+mod double_metaphone;
+mod metaphone;
+mod nysiis;
+mod refined_soundex;
+mod soundex;
+
+pub use self::caverphone::Caverphone;
+pub use self::double_metaphone::DoubleMetaphone;
+pub use self::metaphone::Metaphone;
+pub use self::nysiis::NYSIIS;
+pub use self::refined_soundex::RefinedSoundex;
+pub use self::soundex::Soundex;
+
+/// A base trait for all phonetic encoders.
+pub trait Encoder {
+    /// Encode the given text into its phonetic code.
+    ///
+    ///     use textdistance::phonetic::{Encoder, NYSIIS};
+    ///     assert_eq!(NYSIIS::default().encode("Knight"), "NAGT");
+    ///
+    fn encode(&self, s: &str) -> String;
+}
+
+/// Phonetic match of two texts.
+///
+/// The similarity is 1 if both texts have the same phonetic code and 0 otherwise.
+///
+/// `for_str` encodes both strings as a whole, and `for_words` encodes each word
+/// separately and requires all codes to be the same. Other methods of [`Algorithm`]
+/// work with arbitrary elements that cannot be encoded, so the sequences match
+/// only if they are equal.
+pub struct Phonetic<P> {
+    /// The encoder to use for both inputs.
+    pub encoder: P,
+}
+
+impl<P: Encoder> Phonetic<P> {
+    /// Make a new algorithm comparing phonetic codes produced by the given encoder.
+    pub fn new(encoder: P) -> Self {
+        Self { encoder }
+    }
+}
+
+impl<P: Default> Default for Phonetic<P> {
+    fn default() -> Self {
+        Self {
+            encoder: P::default(),
+        }
+    }
+}
+
+impl<P: Encoder> Algorithm<usize> for Phonetic<P> {
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<usize>
+    where
+        E: Eq + Hash,
+    {
+        Result {
+            abs: usize::from(s1 == s2),
+            is_distance: false,
+            max: 1,
+            len1: s1.len(),
+            len2: s2.len(),
+        }
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<usize> {
+        let c1 = self.encoder.encode(s1);
+        let c2 = self.encoder.encode(s2);
+        Result {
+            abs: usize::from(c1 == c2),
+            is_distance: false,
+            max: 1,
+            len1: c1.chars().count(),
+            len2: c2.chars().count(),
+        }
+    }
+
+    fn for_words(&self, s1: &str, s2: &str) -> Result<usize> {
+        let c1 = encode_words(&self.encoder, s1);
+        let c2 = encode_words(&self.encoder, s2);
+        self.for_vec(&c1, &c2)
+    }
+}
+
+/// Phonetic codes of two texts compared by another algorithm.
+///
+/// `for_str` encodes both strings as a whole and compares the codes
+/// using the inner algorithm's `for_str`. `for_words` encodes each word separately
+/// and compares the sequences of codes using the inner algorithm's `for_vec`.
+/// Other methods of [`Algorithm`] work with arbitrary elements that cannot be encoded,
+/// so they are passed into the inner algorithm as is.
+///
+///     use textdistance::{Algorithm, Hamming};
+///     use textdistance::phonetic::{PhoneticWith, Soundex};
+///
+///     let a = PhoneticWith::new(Soundex::default(), Hamming::default());
+///     assert!(a.for_str("Tymczak", "Tynczak").val() == 0); // T522
+///     assert!(a.for_str("Ashcraft", "Ashcroft").val() == 0); // A261
+///     assert!(a.for_str("Robert", "Rubin").val() == 2); // R163 and R150
+///
+pub struct PhoneticWith<P, A> {
+    /// The encoder to use for both inputs.
+    pub encoder: P,
+
+    /// The algorithm to compare the phonetic codes.
+    pub inner: A,
+}
+
+impl<P, A> PhoneticWith<P, A> {
+    /// Make a new algorithm applying `inner` to the phonetic codes produced by `encoder`.
+    pub fn new(encoder: P, inner: A) -> Self {
+        Self { encoder, inner }
+    }
+}
+
+impl<P: Default, A: Default> Default for PhoneticWith<P, A> {
+    fn default() -> Self {
+        Self {
+            encoder: P::default(),
+            inner: A::default(),
+        }
+    }
+}
+
+impl<P, A, R> Algorithm<R> for PhoneticWith<P, A>
+where
+    P: Encoder,
+    A: Algorithm<R>,
+{
+    fn for_vec<E>(&self, s1: &[E], s2: &[E]) -> Result<R>
+    where
+        E: Eq + Hash,
+    {
+        self.inner.for_vec(s1, s2)
+    }
+
+    fn for_str(&self, s1: &str, s2: &str) -> Result<R> {
+        let c1 = self.encoder.encode(s1);
+        let c2 = self.encoder.encode(s2);
+        self.inner.for_str(&c1, &c2)
+    }
+
+    fn for_words(&self, s1: &str, s2: &str) -> Result<R> {
+        let c1 = encode_words(&self.encoder, s1);
+        let c2 = encode_words(&self.encoder, s2);
+        self.inner.for_vec(&c1, &c2)
+    }
+}
+
+fn encode_words<P: Encoder>(encoder: &P, s: &str) -> Vec<String> {
+    s.split_whitespace().map(|w| encoder.encode(w)).collect()
+}
+
+/// Uppercase ASCII letters of the text, everything else is dropped.
+fn letters(s: &str) -> Vec<u8> {
+    s.bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hamming, Levenshtein};
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Robert", "Rupert", 1)]
+    #[case("Robert", "Rubin", 0)]
+    #[case("", "", 1)]
+    #[case("Ashcraft", "ashcroft", 1)]
+    fn phonetic_str(#[case] s1: &str, #[case] s2: &str, #[case] exp: usize) {
+        let a = Phonetic::new(Soundex::default());
+        assert!(a.for_str(s1, s2).val() == exp);
+    }
+
+    #[test]
+    fn phonetic_words() {
+        let a = Phonetic::new(Soundex::default());
+        assert!(a.for_words("Robert Smith", "Rupert Smyth").val() == 1);
+        assert!(a.for_words("Robert Smith", "Rupert").val() == 0);
+        assert!(a.for_words("Robert Smith", "Smith Robert").val() == 0);
+    }
+
+    #[test]
+    fn phonetic_vec() {
+        let a = Phonetic::new(Soundex::default());
+        assert!(a.for_vec(&[1, 2], &[1, 2]).val() == 1);
+        assert!(a.for_vec(&[1, 2], &[2, 1]).val() == 0);
+    }
+
+    #[test]
+    fn phonetic_with() {
+        let a = PhoneticWith::new(Soundex::default(), Hamming::default());
+        assert!(a.for_str("Robert", "Rubin").val() == 2);
+        assert!(a.for_words("Robert Smith", "Rupert Smyth").val() == 0);
+        assert!(a.for_words("Robert Smith", "Rubin Smyth").val() == 1);
+
+        let a = PhoneticWith::new(DoubleMetaphone::default(), Levenshtein::default());
+        assert!(a.for_str("Smith", "Smyth").val() == 0);
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("abc", "ABC")]
+    #[case("O'Hara", "OHARA")]
+    #[case("Müller", "MLLER")]
+    fn letters_only(#[case] s: &str, #[case] exp: &str) {
+        assert!(letters(s) == exp.as_bytes());
+    }
+}
@@ -0,0 +1,173 @@
+//! Caverphone 2
+use super::Encoder;
+use alloc::string::String;
+
+/// [Caverphone] 2.0 encodes a name as a 10 characters long code.
+///
+/// It was created to match names in the New Zealand electoral rolls,
+/// and so it's tuned for the accent of southern New Zealand.
+///
+///     use textdistance::phonetic::{Caverphone, Encoder};
+///     let c = Caverphone::default();
+///     assert_eq!(c.encode("Thompson"), "TMPSN11111");
+///     assert_eq!(c.encode("Stevenson"), "STFNSN1111");
+///
+/// [Caverphone]: https://en.wikipedia.org/wiki/Caverphone
+#[derive(Default)]
+pub struct Caverphone {}
+
+/// Where in the text a replacement rule is applied.
+enum At {
+    Start,
+    End,
+    Anywhere,
+}
+
+/// Replacements applied in the given order before vowels are replaced.
+/// Uppercase letters are final, digits are placeholders removed at the end.
+const CONSONANTS: &[(At, &str, &str)] = &[
+    (At::End, "e", ""),
+    (At::Start, "cough", "cou2f"),
+    (At::Start, "rough", "rou2f"),
+    (At::Start, "tough", "tou2f"),
+    (At::Start, "enough", "enou2f"),
+    (At::Start, "trough", "trou2f"),
+    (At::Start, "gn", "2n"),
+    (At::End, "mb", "m2"),
+    (At::Anywhere, "cq", "2q"),
+    (At::Anywhere, "ci", "si"),
+    (At::Anywhere, "ce", "se"),
+    (At::Anywhere, "cy", "sy"),
+    (At::Anywhere, "tch", "2ch"),
+    (At::Anywhere, "c", "k"),
+    (At::Anywhere, "q", "k"),
+    (At::Anywhere, "x", "k"),
+    (At::Anywhere, "v", "f"),
+    (At::Anywhere, "dg", "2g"),
+    (At::Anywhere, "tio", "sio"),
+    (At::Anywhere, "tia", "sia"),
+    (At::Anywhere, "d", "t"),
+    (At::Anywhere, "ph", "fh"),
+    (At::Anywhere, "b", "p"),
+    (At::Anywhere, "sh", "s2"),
+    (At::Anywhere, "z", "s"),
+];
+
+/// Replacements applied after vowels are replaced and before consonants are squeezed.
+const SEMIVOWELS: &[(At, &str, &str)] = &[
+    (At::Anywhere, "j", "y"),
+    (At::Start, "y3", "Y3"),
+    (At::Start, "y", "A"),
+    (At::Anywhere, "y", "3"),
+    (At::Anywhere, "3gh3", "3kh3"),
+    (At::Anywhere, "gh", "22"),
+    (At::Anywhere, "g", "k"),
+];
+
+/// Replacements applied after consonants are squeezed.
+const SONORANTS: &[(At, &str, &str)] = &[
+    (At::Anywhere, "w3", "W3"),
+    (At::Anywhere, "wh3", "Wh3"),
+    (At::End, "w", "3"),
+    (At::Anywhere, "w", "2"),
+    (At::Start, "h", "A"),
+    (At::Anywhere, "h", "2"),
+    (At::Anywhere, "r3", "R3"),
+    (At::End, "r", "3"),
+    (At::Anywhere, "r", "2"),
+    (At::Anywhere, "l3", "L3"),
+    (At::End, "l", "3"),
+    (At::Anywhere, "l", "2"),
+    (At::Anywhere, "2", ""),
+    (At::End, "3", "A"),
+    (At::Anywhere, "3", ""),
+];
+
+fn apply(mut s: String, rules: &[(At, &str, &str)]) -> String {
+    for (at, from, to) in rules {
+        s = match at {
+            At::Start => match s.strip_prefix(from) {
+                Some(rest) => to.chars().chain(rest.chars()).collect(),
+                None => s,
+            },
+            At::End => match s.strip_suffix(from) {
+                Some(rest) => rest.chars().chain(to.chars()).collect(),
+                None => s,
+            },
+            At::Anywhere => s.replace(from, to),
+        };
+    }
+    s
+}
+
+/// Replace the first vowel with `A` and all other vowels with `3`.
+fn replace_vowels(s: &str) -> String {
+    s.char_indices()
+        .map(|(i, c)| match c {
+            'a' | 'e' | 'i' | 'o' | 'u' if i == 0 => 'A',
+            'a' | 'e' | 'i' | 'o' | 'u' => '3',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Replace each run of the same consonant with a single uppercase letter.
+fn squeeze(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut last = None;
+    for c in s.chars() {
+        if matches!(c, 's' | 't' | 'p' | 'k' | 'f' | 'm' | 'n') {
+            if last != Some(c) {
+                result.push(c.to_ascii_uppercase());
+            }
+        } else {
+            result.push(c);
+        }
+        last = Some(c);
+    }
+    result
+}
+
+impl Encoder for Caverphone {
+    fn encode(&self, s: &str) -> String {
+        let mut s: String = s
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        s = apply(s, CONSONANTS);
+        s = replace_vowels(&s);
+        s = apply(s, SEMIVOWELS);
+        s = squeeze(&s);
+        s = apply(s, SONORANTS);
+        while s.len() < 10 {
+            s.push('1');
+        }
+        s.truncate(10);
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "1111111111")]
+    #[case("Thompson", "TMPSN11111")]
+    #[case("Lee", "LA11111111")]
+    #[case("Stevenson", "STFNSN1111")]
+    #[case("Peter", "PTA1111111")]
+    #[case("Tough", "TF11111111")]
+    #[case("Trough", "TRF1111111")]
+    #[case("Mclaverty", "MKLFTA1111")]
+    #[case("Lindsey", "LNTSA11111")]
+    #[case("Stephen", "STFN111111")]
+    #[case("Wilson", "WSN1111111")]
+    fn encode(#[case] s: &str, #[case] exp: &str) {
+        let act = Caverphone::default().encode(s);
+        assert!(act == exp, "caverphone({}) is {}, not {}", s, act, exp);
+    }
+}
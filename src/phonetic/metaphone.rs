@@ -0,0 +1,264 @@
+//! Metaphone
+use super::{is_vowel, letters, Encoder};
+use alloc::string::String;
+
+/// [Metaphone] is an improvement over [`Soundex`] that knows English pronunciation rules.
+///
+/// The code consists of consonant sounds, with `0` standing for "th" and `X` for "sh".
+///
+///     use textdistance::phonetic::{Encoder, Metaphone};
+///     let m = Metaphone::default();
+///     assert_eq!(m.encode("Thompson"), "0MPS");
+///     assert_eq!(m.encode("Knight"), "NT");
+///
+/// [Metaphone]: https://en.wikipedia.org/wiki/Metaphone
+/// [`Soundex`]: super::Soundex
+pub struct Metaphone {
+    /// The maximum length of the code. Zero means no limit. Default: 4.
+    pub max_length: usize,
+}
+
+impl Default for Metaphone {
+    fn default() -> Self {
+        Self { max_length: 4 }
+    }
+}
+
+struct Word {
+    s: alloc::vec::Vec<u8>,
+}
+
+impl Word {
+    fn at(&self, i: usize) -> Option<u8> {
+        self.s.get(i).copied()
+    }
+
+    fn prev_is(&self, i: usize, c: u8) -> bool {
+        i > 0 && self.s[i - 1] == c
+    }
+
+    fn next_is(&self, i: usize, c: u8) -> bool {
+        self.at(i + 1) == Some(c)
+    }
+
+    fn is_front_vowel(&self, i: usize) -> bool {
+        matches!(self.at(i), Some(b'E' | b'I' | b'Y'))
+    }
+
+    fn is_vowel(&self, i: usize) -> bool {
+        self.at(i).is_some_and(is_vowel)
+    }
+
+    fn starts_with(&self, i: usize, p: &[u8]) -> bool {
+        self.s[i..].starts_with(p)
+    }
+
+    fn is_last(&self, i: usize) -> bool {
+        i + 1 == self.s.len()
+    }
+
+    /// Push the code of the letter at `i`. Returns how many following letters to skip.
+    fn push_code(&self, i: usize, result: &mut String) -> usize {
+        let c = self.s[i];
+        let mut skip = 0;
+        match c {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if i == 0 {
+                    result.push(char::from(c));
+                }
+            }
+            b'B' => {
+                // silent in "-mb" at the end
+                if !(self.prev_is(i, b'M') && self.is_last(i)) {
+                    result.push('B');
+                }
+            }
+            b'C' => {
+                if self.prev_is(i, b'S') && self.is_front_vowel(i + 1) {
+                    // silent in "sci", "sce", "scy"
+                } else if self.starts_with(i, b"CIA") {
+                    result.push('X');
+                } else if self.is_front_vowel(i + 1) {
+                    result.push('S');
+                } else if self.prev_is(i, b'S') && self.next_is(i, b'H') {
+                    result.push('K');
+                } else if self.next_is(i, b'H') {
+                    if i == 0 && self.s.len() > 3 && self.is_vowel(2) {
+                        result.push('K');
+                    } else {
+                        result.push('X');
+                    }
+                } else {
+                    result.push('K');
+                }
+            }
+            b'D' => {
+                if self.next_is(i, b'G') && self.is_front_vowel(i + 2) {
+                    result.push('J');
+                    skip = 2;
+                } else {
+                    result.push('T');
+                }
+            }
+            b'G' => {
+                let silent = (self.next_is(i, b'H') && !self.is_vowel(i + 2))
+                    || (i > 0 && (self.starts_with(i, b"GN") || self.starts_with(i, b"GNED")));
+                if silent {
+                } else if !self.prev_is(i, b'G') && self.is_front_vowel(i + 1) {
+                    result.push('J');
+                } else {
+                    result.push('K');
+                }
+            }
+            b'H' => {
+                let after_varson =
+                    i > 0 && matches!(self.s[i - 1], b'C' | b'S' | b'P' | b'T' | b'G');
+                if !self.is_last(i) && !after_varson && self.is_vowel(i + 1) {
+                    result.push('H');
+                }
+            }
+            b'K' => {
+                if !self.prev_is(i, b'C') {
+                    result.push('K');
+                }
+            }
+            b'P' => {
+                if self.next_is(i, b'H') {
+                    result.push('F');
+                } else {
+                    result.push('P');
+                }
+            }
+            b'Q' => result.push('K'),
+            b'S' => {
+                if self.starts_with(i, b"SH")
+                    || self.starts_with(i, b"SIO")
+                    || self.starts_with(i, b"SIA")
+                {
+                    result.push('X');
+                } else {
+                    result.push('S');
+                }
+            }
+            b'T' => {
+                if self.starts_with(i, b"TIA") || self.starts_with(i, b"TIO") {
+                    result.push('X');
+                } else if self.starts_with(i, b"TCH") {
+                    // silent, "ch" makes the sound
+                } else if self.next_is(i, b'H') {
+                    result.push('0');
+                } else {
+                    result.push('T');
+                }
+            }
+            b'V' => result.push('F'),
+            b'W' | b'Y' => {
+                if self.is_vowel(i + 1) {
+                    result.push(char::from(c));
+                }
+            }
+            b'X' => result.push_str("KS"),
+            b'Z' => result.push('S'),
+            // F, J, L, M, N, R
+            _ => result.push(char::from(c)),
+        }
+        skip
+    }
+}
+
+impl Metaphone {
+    fn skip_initial(s: &[u8]) -> usize {
+        match s {
+            [b'A', b'E', ..] | [b'G' | b'K' | b'P', b'N', ..] | [b'W', b'R', ..] => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl Encoder for Metaphone {
+    fn encode(&self, s: &str) -> String {
+        let mut s = letters(s);
+        if s.len() <= 1 {
+            return s.into_iter().map(char::from).collect();
+        }
+        match s[..2] {
+            [b'W', b'H'] => {
+                s.remove(1);
+            }
+            [b'X', _] => s[0] = b'S',
+            _ => {
+                let skip = Self::skip_initial(&s);
+                s.drain(..skip);
+            }
+        }
+        let w = Word { s };
+        let max_length = if self.max_length == 0 {
+            usize::MAX
+        } else {
+            self.max_length
+        };
+
+        let mut result = String::new();
+        let mut skip = 0;
+        for (i, &c) in w.s.iter().enumerate() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            if result.len() >= max_length {
+                break;
+            }
+            // duplicate letters are encoded once, except C
+            if c != b'C' && w.prev_is(i, c) {
+                continue;
+            }
+            skip = w.push_code(i, &mut result);
+        }
+        result.truncate(max_length);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "")]
+    #[case("a", "A")]
+    #[case("Thompson", "0MPS")]
+    #[case("Knight", "NT")]
+    #[case("Smith", "SM0")]
+    #[case("Schmidt", "SKMT")]
+    #[case("Aero", "ER")]
+    #[case("Wright", "RT")]
+    #[case("White", "WT")]
+    #[case("Xavier", "SFR")]
+    #[case("Joanne", "JN")]
+    #[case("Lawrence", "LRNS")]
+    #[case("Lorenza", "LRNS")]
+    #[case("Gary", "KR")]
+    #[case("Cahra", "KR")]
+    #[case("quick", "KK")]
+    #[case("cookie", "KK")]
+    #[case("dumb", "TM")]
+    #[case("science", "SNS")]
+    #[case("edge", "EJ")]
+    #[case("laugh", "L")]
+    #[case("Christina", "XRST")]
+    #[case("nation", "NXN")]
+    #[case("physics", "FSKS")]
+    fn encode(#[case] s: &str, #[case] exp: &str) {
+        let act = Metaphone::default().encode(s);
+        assert!(act == exp, "metaphone({}) is {}, not {}", s, act, exp);
+    }
+
+    #[test]
+    fn max_length() {
+        let m = Metaphone { max_length: 0 };
+        assert!(m.encode("Christina") == "XRSTN");
+        assert!(m.encode("Washington") == "WXNKTN");
+    }
+}
@@ -0,0 +1,74 @@
+//! Refined Soundex
+use super::{letters, Encoder};
+use alloc::string::String;
+
+/// Refined Soundex is a variation of [`Soundex`] with more groups of letters
+/// and without a length limit.
+///
+/// Unlike Soundex, vowels are encoded as `0` and so separate the consonants.
+///
+///     use textdistance::phonetic::{Encoder, RefinedSoundex};
+///     let s = RefinedSoundex::default();
+///     assert_eq!(s.encode("testing"), "T6036084");
+///
+/// [`Soundex`]: super::Soundex
+#[derive(Default)]
+pub struct RefinedSoundex {}
+
+fn code(c: u8) -> u8 {
+    match c {
+        b'B' | b'P' => b'1',
+        b'F' | b'V' => b'2',
+        b'C' | b'K' | b'S' => b'3',
+        b'G' | b'J' => b'4',
+        b'Q' | b'X' | b'Z' => b'5',
+        b'D' | b'T' => b'6',
+        b'L' => b'7',
+        b'M' | b'N' => b'8',
+        b'R' => b'9',
+        _ => b'0',
+    }
+}
+
+impl Encoder for RefinedSoundex {
+    fn encode(&self, s: &str) -> String {
+        let s = letters(s);
+        let Some(&first) = s.first() else {
+            return String::new();
+        };
+        let mut result = String::with_capacity(s.len() + 1);
+        result.push(char::from(first));
+        let mut last = None;
+        for &c in &s {
+            let current = code(c);
+            if last != Some(current) {
+                result.push(char::from(current));
+                last = Some(current);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "")]
+    #[case("testing", "T6036084")]
+    #[case("TESTING", "T6036084")]
+    #[case("The", "T60")]
+    #[case("quick", "Q503")]
+    #[case("brown", "B1908")]
+    #[case("fox", "F205")]
+    #[case("jumped", "J408106")]
+    #[case("over", "O0209")]
+    #[case("lazy", "L7050")]
+    #[case("dogs", "D6043")]
+    fn encode(#[case] s: &str, #[case] exp: &str) {
+        assert!(RefinedSoundex::default().encode(s) == exp);
+    }
+}
@@ -0,0 +1,104 @@
+//! American Soundex
+use super::{letters, Encoder};
+use alloc::string::String;
+
+/// [Soundex] encodes a word as its first letter followed by three digits.
+///
+/// Similar consonants share the same digit, vowels are dropped, and the code
+/// is padded with zeros or truncated to `max_length`.
+///
+///     use textdistance::phonetic::{Encoder, Soundex};
+///     let s = Soundex::default();
+///     assert_eq!(s.encode("Ashcraft"), "A261");
+///     assert_eq!(s.encode("Tymczak"), "T522");
+///
+/// [Soundex]: https://en.wikipedia.org/wiki/Soundex
+pub struct Soundex {
+    /// The length of the code, including the first letter. Default: 4.
+    pub max_length: usize,
+}
+
+impl Default for Soundex {
+    fn default() -> Self {
+        Self { max_length: 4 }
+    }
+}
+
+/// The digit for the letter or `None` for vowels and H, W, Y.
+fn code(c: u8) -> Option<u8> {
+    match c {
+        b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+        b'D' | b'T' => Some(b'3'),
+        b'L' => Some(b'4'),
+        b'M' | b'N' => Some(b'5'),
+        b'R' => Some(b'6'),
+        _ => None,
+    }
+}
+
+impl Encoder for Soundex {
+    fn encode(&self, s: &str) -> String {
+        let s = letters(s);
+        let Some(&first) = s.first() else {
+            return String::new();
+        };
+        let mut result = String::with_capacity(self.max_length);
+        result.push(char::from(first));
+        let mut last = code(first);
+        for &c in &s[1..] {
+            if result.len() >= self.max_length {
+                break;
+            }
+            let current = code(c);
+            if let Some(digit) = current {
+                if current != last {
+                    result.push(char::from(digit));
+                }
+            }
+            // H and W don't separate consonants with the same code, vowels do.
+            if c != b'H' && c != b'W' {
+                last = current;
+            }
+        }
+        while result.len() < self.max_length {
+            result.push('0');
+        }
+        result.truncate(self.max_length);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "")]
+    #[case("A", "A000")]
+    #[case("Robert", "R163")]
+    #[case("Rupert", "R163")]
+    #[case("Rubin", "R150")]
+    #[case("Ashcraft", "A261")]
+    #[case("Ashcroft", "A261")]
+    #[case("Tymczak", "T522")]
+    #[case("Pfister", "P236")]
+    #[case("Honeyman", "H555")]
+    #[case("Lee", "L000")]
+    #[case("Gutierrez", "G362")]
+    #[case("Jackson", "J250")]
+    #[case("O'Hara", "O600")]
+    #[case("Washington", "W252")]
+    fn encode(#[case] s: &str, #[case] exp: &str) {
+        assert!(Soundex::default().encode(s) == exp);
+    }
+
+    #[test]
+    fn max_length() {
+        let s = Soundex { max_length: 6 };
+        assert!(s.encode("Washington") == "W25235");
+        assert!(s.encode("Lee") == "L00000");
+    }
+}
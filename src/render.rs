@@ -0,0 +1,359 @@
+//! Rendering of differences between two texts.
+//!
+//! [`Diff`] splits both texts into tokens (characters, words, or lines), aligns them
+//! using [`LCSSeq`] or [`Levenshtein`], and shows the difference as a unified diff,
+//! inline markup, ANSI-colored text for terminals, or HTML.
+//!
+//!     use textdistance::render::{Alignment, Diff, Granularity};
+//!
+//!     let diff = Diff::new(
+//!         "the quick brown fox",
+//!         "the lazy brown dog",
+//!         Granularity::Words,
+//!         Alignment::LCSSeq,
+//!     );
+//!     assert!(diff.inline() == "the [-quick-] {+lazy+} brown [-fox-] {+dog+}");
+//!     assert!(diff.html() == "the <del>quick</del> <ins>lazy</ins> brown <del>fox</del> <ins>dog</ins>");
+//!
+use crate::diff::{group, Hunk, Tag};
+use crate::{LCSSeq, Levenshtein};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// How to split texts into tokens.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Granularity {
+    /// Each character is a token, like in `for_str`.
+    #[default]
+    Chars,
+
+    /// Words separated by whitespace are tokens, like in `for_words`.
+    /// The whitespace is rendered as a single space.
+    Words,
+
+    /// Each line is a token. Line endings are rendered as `\n`.
+    Lines,
+}
+
+impl Granularity {
+    /// Split the text into tokens.
+    pub fn split(self, s: &str) -> Vec<&str> {
+        match self {
+            Self::Chars => s
+                .char_indices()
+                .map(|(i, c)| &s[i..i + c.len_utf8()])
+                .collect(),
+            Self::Words => s.split_whitespace().collect(),
+            Self::Lines => s.lines().collect(),
+        }
+    }
+
+    /// The text to put between tokens when rendering.
+    pub fn separator(self) -> &'static str {
+        match self {
+            Self::Chars => "",
+            Self::Words => " ",
+            Self::Lines => "\n",
+        }
+    }
+}
+
+/// How to find which tokens are kept.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Alignment {
+    /// Keep the longest common subsequence of tokens, see [`LCSSeq::diff`].
+    /// Changed tokens tend to be grouped together.
+    #[default]
+    LCSSeq,
+
+    /// Keep the tokens of the cheapest edit, see [`Levenshtein::diff`].
+    /// Prefers replacing a token to deleting and inserting it.
+    Levenshtein,
+}
+
+/// Aligned tokens of two texts.
+pub struct Diff<'a> {
+    /// Tokens of the first text.
+    pub tokens1: Vec<&'a str>,
+
+    /// Tokens of the second text.
+    pub tokens2: Vec<&'a str>,
+
+    /// The text to put between tokens in inline outputs.
+    pub separator: &'a str,
+
+    /// The difference between the tokens.
+    pub hunks: Vec<Hunk>,
+}
+
+impl<'a> Diff<'a> {
+    /// Split both texts into tokens and align them.
+    pub fn new(s1: &'a str, s2: &'a str, granularity: Granularity, alignment: Alignment) -> Self {
+        Self::from_tokens(
+            granularity.split(s1),
+            granularity.split(s2),
+            granularity.separator(),
+            alignment,
+        )
+    }
+
+    /// Align two sequences of tokens.
+    ///
+    ///     use textdistance::render::{Alignment, Diff};
+    ///
+    ///     let diff = Diff::from_tokens(vec!["a", "b"], vec!["a", "c"], ", ", Alignment::Levenshtein);
+    ///     assert!(diff.inline() == "a, [-b-], {+c+}");
+    ///
+    pub fn from_tokens(
+        tokens1: Vec<&'a str>,
+        tokens2: Vec<&'a str>,
+        separator: &'a str,
+        alignment: Alignment,
+    ) -> Self {
+        let hunks = match alignment {
+            Alignment::LCSSeq => LCSSeq::default().diff(&tokens1, &tokens2),
+            Alignment::Levenshtein => Levenshtein::default().diff(&tokens1, &tokens2),
+        };
+        Self {
+            tokens1,
+            tokens2,
+            separator,
+            hunks,
+        }
+    }
+
+    /// Render as a unified diff, with each token on its own line
+    /// and up to `context` unchanged tokens around each change.
+    ///
+    /// The output is the same as of `difflib.unified_diff` in Python
+    /// (with `lineterm=""` and the lines joined by `\n`). If there are no changes,
+    /// the result is empty.
+    ///
+    ///     use textdistance::render::{Alignment, Diff, Granularity};
+    ///
+    ///     let diff = Diff::new("a\nb\nc", "a\nc", Granularity::Lines, Alignment::LCSSeq);
+    ///     let exp = "--- old\n+++ new\n@@ -1,3 +1,2 @@\n a\n-b\n c\n";
+    ///     assert!(diff.unified("old", "new", 3) == exp);
+    ///
+    pub fn unified(&self, name1: &str, name2: &str, context: usize) -> String {
+        let groups = group(self.hunks.clone(), context);
+        if groups.is_empty() {
+            return String::new();
+        }
+        let mut out = format!("--- {name1}\n+++ {name2}\n");
+        for hunks in groups {
+            let first = &hunks[0];
+            let last = &hunks[hunks.len() - 1];
+            out.push_str("@@ -");
+            out.push_str(&unified_range(first.range1.start, last.range1.end));
+            out.push_str(" +");
+            out.push_str(&unified_range(first.range2.start, last.range2.end));
+            out.push_str(" @@\n");
+            for hunk in hunks {
+                if hunk.tag == Tag::Equal {
+                    push_lines(&mut out, ' ', &self.tokens1[hunk.range1]);
+                    continue;
+                }
+                push_lines(&mut out, '-', &self.tokens1[hunk.range1]);
+                push_lines(&mut out, '+', &self.tokens2[hunk.range2]);
+            }
+        }
+        out
+    }
+
+    /// Render with deleted tokens in `[-...-]` and inserted ones in `{+...+}`,
+    /// like `wdiff` does.
+    pub fn inline(&self) -> String {
+        self.render(("[-", "-]"), ("{+", "+}"), |s, out| out.push_str(s))
+    }
+
+    /// Render for a terminal, with deleted tokens in red and inserted ones in green.
+    ///
+    ///     use textdistance::render::{Alignment, Diff, Granularity};
+    ///
+    ///     let diff = Diff::new("ab", "ac", Granularity::Chars, Alignment::LCSSeq);
+    ///     assert!(diff.ansi() == "a\x1b[31mb\x1b[0m\x1b[32mc\x1b[0m");
+    ///
+    pub fn ansi(&self) -> String {
+        self.render(
+            ("\x1b[31m", "\x1b[0m"),
+            ("\x1b[32m", "\x1b[0m"),
+            |s, out| out.push_str(s),
+        )
+    }
+
+    /// Render as HTML, with deleted tokens in `<del>` and inserted ones in `<ins>`.
+    /// The tokens are escaped.
+    pub fn html(&self) -> String {
+        self.render(("<del>", "</del>"), ("<ins>", "</ins>"), escape_html)
+    }
+
+    /// Render all tokens in order, wrapping changed ones into the given markers.
+    fn render<F>(&self, del: (&str, &str), ins: (&str, &str), escape: F) -> String
+    where
+        F: Fn(&str, &mut String),
+    {
+        let mut out = String::new();
+        let mut started = false;
+        let mut push = |tokens: &[&str], marks: Option<(&str, &str)>| {
+            if tokens.is_empty() {
+                return;
+            }
+            if started {
+                out.push_str(self.separator);
+            }
+            started = true;
+            if let Some((open, _)) = marks {
+                out.push_str(open);
+            }
+            for (i, token) in tokens.iter().enumerate() {
+                if i != 0 {
+                    out.push_str(self.separator);
+                }
+                escape(token, &mut out);
+            }
+            if let Some((_, close)) = marks {
+                out.push_str(close);
+            }
+        };
+        for hunk in &self.hunks {
+            if hunk.tag == Tag::Equal {
+                push(&self.tokens1[hunk.range1.clone()], None);
+                continue;
+            }
+            push(&self.tokens1[hunk.range1.clone()], Some(del));
+            push(&self.tokens2[hunk.range2.clone()], Some(ins));
+        }
+        out
+    }
+}
+
+/// Add each token as a line of a unified diff.
+fn push_lines(out: &mut String, prefix: char, tokens: &[&str]) {
+    for token in tokens {
+        out.push(prefix);
+        out.push_str(token);
+        out.push('\n');
+    }
+}
+
+/// A range of lines in the hunk header of a unified diff.
+fn unified_range(start: usize, end: usize) -> String {
+    match end - start {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        len => format!("{},{len}", start + 1),
+    }
+}
+
+fn escape_html(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "", Granularity::Chars, "")]
+    #[case("abc", "abc", Granularity::Chars, "abc")]
+    #[case(
+        "kitten",
+        "sitting",
+        Granularity::Chars,
+        "[-k-]{+s+}itt[-e-]{+i+}n{+g+}"
+    )]
+    #[case("a b  c", "a c", Granularity::Words, "a [-b-] c")]
+    #[case("one\ntwo", "one\n2\ntwo", Granularity::Lines, "one\n{+2+}\ntwo")]
+    fn inline(
+        #[case] s1: &str,
+        #[case] s2: &str,
+        #[case] granularity: Granularity,
+        #[case] exp: &str,
+    ) {
+        let diff = Diff::new(s1, s2, granularity, Alignment::LCSSeq);
+        assert!(diff.inline() == exp);
+    }
+
+    #[rstest]
+    #[case(Alignment::LCSSeq, "abc", "bcd", "[-a-]bc{+d+}")]
+    #[case(Alignment::Levenshtein, "abc", "bcd", "[-a-]bc{+d+}")]
+    #[case(Alignment::LCSSeq, "ab", "ba", "[-a-]b{+a+}")]
+    #[case(Alignment::Levenshtein, "ab", "ba", "[-ab-]{+ba+}")]
+    fn alignments(
+        #[case] alignment: Alignment,
+        #[case] s1: &str,
+        #[case] s2: &str,
+        #[case] exp: &str,
+    ) {
+        let diff = Diff::new(s1, s2, Granularity::Chars, alignment);
+        assert!(diff.inline() == exp);
+    }
+
+    #[test]
+    fn html() {
+        let diff = Diff::new("a<b", "a>b & c", Granularity::Words, Alignment::LCSSeq);
+        assert!(diff.html() == "<del>a&lt;b</del> <ins>a&gt;b &amp; c</ins>");
+    }
+
+    #[test]
+    fn unified() {
+        // parity with difflib
+        let s1 = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten";
+        let s2 = "one\n2\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\neleven";
+        let diff = Diff::new(s1, s2, Granularity::Lines, Alignment::LCSSeq);
+        let exp = "--- a.txt\n+++ b.txt\n\
+            @@ -1,4 +1,4 @@\n one\n-two\n+2\n three\n four\n\
+            @@ -9,2 +9,3 @@\n nine\n ten\n+eleven\n";
+        assert!(diff.unified("a.txt", "b.txt", 2) == exp);
+        let diff = Diff::new(s1, s1, Granularity::Lines, Alignment::LCSSeq);
+        assert!(diff.unified("a.txt", "b.txt", 2).is_empty());
+    }
+
+    #[test]
+    fn unified_empty() {
+        let diff = Diff::new("", "a\nb", Granularity::Lines, Alignment::LCSSeq);
+        assert!(diff.unified("a", "b", 3) == "--- a\n+++ b\n@@ -0,0 +1,2 @@\n+a\n+b\n");
+    }
+
+    /// Remove the marked segments and keep the contents of the other markers.
+    fn side(out: &str, (open, close): (&str, &str), keep: (&str, &str)) -> String {
+        let mut res = String::new();
+        let mut rest = out;
+        while let Some(start) = rest.find(open) {
+            res.push_str(&rest[..start]);
+            let end = rest[start..].find(close).unwrap() + start;
+            rest = &rest[end + close.len()..];
+        }
+        res.push_str(rest);
+        res.replace(keep.0, "").replace(keep.1, "")
+    }
+
+    proptest! {
+        #[test]
+        fn prop_inline_words(s1 in "[ab ]*", s2 in "[ab ]*") {
+            let words1: Vec<&str> = s1.split_whitespace().collect();
+            let words2: Vec<&str> = s2.split_whitespace().collect();
+            for alignment in [Alignment::LCSSeq, Alignment::Levenshtein] {
+                let out = Diff::new(&s1, &s2, Granularity::Words, alignment).inline();
+                let old = side(&out, ("{+", "+}"), ("[-", "-]"));
+                let new = side(&out, ("[-", "-]"), ("{+", "+}"));
+                prop_assert!(old.split_whitespace().collect::<Vec<_>>() == words1.clone());
+                prop_assert!(new.split_whitespace().collect::<Vec<_>>() == words2.clone());
+            }
+        }
+    }
+}
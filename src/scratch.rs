@@ -0,0 +1,219 @@
+//! Reusable buffers for allocation-free scoring
+use crate::{Algorithm, Result};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Reusable buffers for algorithms implementing [`WithScratch`].
+///
+/// Most edit-based algorithms allocate memory on each call: for the characters
+/// of the input strings and for the rows of the dynamic programming matrix.
+/// When comparing many pairs of strings, create a `Scratch` once and pass it
+/// to each call. The buffers grow to fit the longest input seen so far,
+/// and after that no heap allocation happens at all.
+///
+///     use textdistance::{Levenshtein, Scratch, WithScratch};
+///     let a = Levenshtein::default();
+///     let mut scratch = Scratch::default();
+///     for (s1, s2) in [("kitten", "sitting"), ("flaw", "lawn")] {
+///         let res = a.for_str_with(&mut scratch, s1, s2);
+///         assert!(res.val() == textdistance::str::levenshtein(s1, s2));
+///     }
+///
+/// The same scratch can be shared between different algorithms.
+/// Creating an empty scratch doesn't allocate.
+#[derive(Default)]
+pub struct Scratch {
+    pub(crate) chars1: Vec<char>,
+    pub(crate) chars2: Vec<char>,
+    pub(crate) rows: Vec<usize>,
+    pub(crate) irows: Vec<isize>,
+    pub(crate) flags1: Vec<bool>,
+    pub(crate) flags2: Vec<bool>,
+    /// The hash of an element -> the last id with that hash.
+    #[cfg(feature = "std")]
+    pub(crate) ids: HashMap<u64, usize>,
+    /// The position of the first occurrence of each id (in the second sequence if true)
+    /// and the previous id with the same hash.
+    #[cfg(feature = "std")]
+    pub(crate) id_reps: Vec<((bool, usize), Option<usize>)>,
+    #[cfg(feature = "std")]
+    pub(crate) ids1: Vec<usize>,
+    #[cfg(feature = "std")]
+    pub(crate) ids2: Vec<usize>,
+    #[cfg(feature = "std")]
+    pub(crate) last_rows: Vec<Option<(usize, usize)>>,
+    #[cfg(feature = "std")]
+    pub(crate) saved_rows: Vec<usize>,
+}
+
+/// Algorithms that can keep their intermediate state in a [`Scratch`]
+/// instead of allocating memory on each call.
+///
+/// The results are the same as of the corresponding methods of [`Algorithm`].
+pub trait WithScratch<R>: Algorithm<R> {
+    /// Calculate distance/similarity for vectors using the given buffers.
+    ///
+    ///     use textdistance::{LCSSeq, Scratch, WithScratch};
+    ///     let mut scratch = Scratch::default();
+    ///     let res = LCSSeq::default().for_vec_with(&mut scratch, &[1, 2, 3], &[1, 3, 2, 4]);
+    ///     assert!(res.val() == 2);
+    ///
+    fn for_vec_with<E>(&self, scratch: &mut Scratch, s1: &[E], s2: &[E]) -> Result<R>
+    where
+        E: Eq + Hash;
+
+    /// Calculate distance/similarity for strings using the given buffers.
+    ///
+    ///     use textdistance::{LCSSeq, Scratch, WithScratch};
+    ///     let mut scratch = Scratch::default();
+    ///     let res = LCSSeq::default().for_str_with(&mut scratch, "abc", "acbd");
+    ///     assert!(res.val() == 2);
+    ///
+    fn for_str_with(&self, scratch: &mut Scratch, s1: &str, s2: &str) -> Result<R> {
+        with_chars(scratch, s1, s2, |scratch, s1, s2| {
+            self.for_vec_with(scratch, s1, s2)
+        })
+    }
+}
+
+/// Split both strings into characters stored in the scratch and call the function on them.
+pub(crate) fn with_chars<T, F>(scratch: &mut Scratch, s1: &str, s2: &str, f: F) -> T
+where
+    F: FnOnce(&mut Scratch, &[char], &[char]) -> T,
+{
+    let mut chars1 = core::mem::take(&mut scratch.chars1);
+    let mut chars2 = core::mem::take(&mut scratch.chars2);
+    chars1.clear();
+    chars1.extend(s1.chars());
+    chars2.clear();
+    chars2.extend(s2.chars());
+    let res = f(scratch, &chars1, &chars2);
+    scratch.chars1 = chars1;
+    scratch.chars2 = chars2;
+    res
+}
+
+/// Replace the elements of both sequences by ids from 0 to the number of distinct elements,
+/// stored in `ids1` and `ids2` of the scratch. Returns the number of distinct elements.
+///
+/// The elements are found by their hashes and compared with the first occurrence
+/// of each id, so that the map doesn't depend on the element type and can be reused.
+#[cfg(feature = "std")]
+pub(crate) fn element_ids<E: Eq + Hash>(scratch: &mut Scratch, s1: &[E], s2: &[E]) -> usize {
+    let Scratch {
+        ids,
+        id_reps: reps,
+        ids1,
+        ids2,
+        ..
+    } = scratch;
+    ids.clear();
+    reps.clear();
+    ids1.clear();
+    ids2.clear();
+    let elem = |(second, i): (bool, usize)| if second { &s2[i] } else { &s1[i] };
+    for (second, seq, out) in [(false, s1, ids1), (true, s2, ids2)] {
+        for (i, e) in seq.iter().enumerate() {
+            let hash = ids.hasher().hash_one(e);
+            let mut id = ids.get(&hash).copied();
+            while let Some(cur) = id {
+                let (pos, prev) = reps[cur];
+                if elem(pos) == e {
+                    break;
+                }
+                id = prev;
+            }
+            let id = if let Some(id) = id {
+                id
+            } else {
+                let new = reps.len();
+                let prev = ids.insert(hash, new);
+                reps.push(((second, i), prev));
+                new
+            };
+            out.push(id);
+        }
+    }
+    reps.len()
+}
+
+/// Make the buffer `len` elements long and fill it with the value.
+///
+/// Algorithms keep all rows of a matrix in a single buffer instead of swapping
+/// separate vectors, so the capacity of each buffer only grows.
+pub(crate) fn reset<T: Clone>(buf: &mut Vec<T>, len: usize, value: T) {
+    buf.clear();
+    buf.resize(len, value);
+}
+
+/// Split the buffer of two rows into the previous and the current row
+/// for the step `i` of the algorithm.
+pub(crate) fn two_rows<T>(buf: &mut [T], width: usize, i: usize) -> (&mut [T], &mut [T]) {
+    let (a, b) = buf.split_at_mut(width);
+    if i.is_multiple_of(2) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::*;
+    use assert2::assert;
+    use proptest::prelude::*;
+
+    #[test]
+    fn shared() {
+        let mut scratch = Scratch::default();
+        let lev = Levenshtein::default();
+        let lcs = LCSSeq::default();
+        assert!(lev.for_str_with(&mut scratch, "kitten", "sitting").val() == 3);
+        assert!(lcs.for_str_with(&mut scratch, "test", "text").val() == 3);
+        assert!(lev.for_str_with(&mut scratch, "", "abc").val() == 3);
+        assert!(lcs.for_str_with(&mut scratch, "abc", "").val() == 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn element_ids() {
+        let mut scratch = Scratch::default();
+        assert!(crate::scratch::element_ids(&mut scratch, &[5, 7, 5], &[7, 9]) == 3);
+        assert!(scratch.ids1 == [0, 1, 0]);
+        assert!(scratch.ids2 == [1, 2]);
+        assert!(crate::scratch::element_ids(&mut scratch, &["a"], &["a"]) == 1);
+        assert!(scratch.ids2 == [0]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_same_results(pairs in prop::collection::vec((".{0,80}", ".{0,80}"), 1..5)) {
+            let mut scratch = Scratch::default();
+            for (s1, s2) in &pairs {
+                let exp = Levenshtein::default().for_str(s1, s2).val();
+                prop_assert!(Levenshtein::default().for_str_with(&mut scratch, s1, s2).val() == exp);
+                let exp = LCSSeq::default().for_str(s1, s2).val();
+                prop_assert!(LCSSeq::default().for_str_with(&mut scratch, s1, s2).val() == exp);
+                let exp = LCSStr::default().for_str(s1, s2).val();
+                prop_assert!(LCSStr::default().for_str_with(&mut scratch, s1, s2).val() == exp);
+                let exp = SmithWaterman::default().for_str(s1, s2).val();
+                prop_assert!(SmithWaterman::default().for_str_with(&mut scratch, s1, s2).val() == exp);
+                let exp = Jaro::default().for_str(s1, s2).nval();
+                prop_assert!(Jaro::default().for_str_with(&mut scratch, s1, s2).nval() == exp);
+                for restricted in [false, true] {
+                    let a = DamerauLevenshtein { restricted, ..Default::default() };
+                    let exp = a.for_iter(s1.chars(), s2.chars()).val();
+                    prop_assert!(a.for_str_with(&mut scratch, s1, s2).val() == exp);
+                    let exp = a.for_vec(s1.as_bytes(), s2.as_bytes()).val();
+                    prop_assert!(a.for_vec_with(&mut scratch, s1.as_bytes(), s2.as_bytes()).val() == exp);
+                }
+            }
+        }
+    }
+}
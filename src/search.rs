@@ -0,0 +1,622 @@
+//! Approximate search of a short pattern in a long text.
+//!
+//! Algorithms in this crate compare two whole sequences. The searchers here
+//! instead find where a pattern occurs inside of a text with a few errors,
+//! like when locating a phrase in a document after OCR or grepping logs with typos.
+//!
+//!     use textdistance::search::{Match, Sellers};
+//!
+//!     let s = Sellers { max_distance: 1 };
+//!     let text = "the quick brwn fox";
+//!     let found = s.find_str("brown", text);
+//!     assert!(found == vec![Match { start: 10, end: 14, distance: 1 }]);
+//!     assert!(&text[10..14] == "brwn");
+//!
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A part of the text that matches the pattern.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Match {
+    /// The index of the first element of the match in the text.
+    pub start: usize,
+
+    /// The index after the last element of the match in the text.
+    pub end: usize,
+
+    /// The number of errors: how many elements need to be inserted, deleted, or replaced
+    /// to turn the pattern into the matched part of the text.
+    pub distance: usize,
+}
+
+/// [Sellers' algorithm] finds all parts of the text within the [`Levenshtein`] distance
+/// of `max_distance` from the pattern.
+///
+/// It's the same dynamic programming as for [`Levenshtein`] except that the match
+/// may start anywhere in the text for free. Only the rows that can still be within
+/// `max_distance` are calculated (Ukkonen's cut-off), so the search takes about
+/// `O(max_distance * text length)` time on typical texts and `O(pattern length)` memory.
+///
+/// Each position in the text where the pattern ends with few enough errors is a match,
+/// so the matches usually come in runs of neighboring positions.
+/// [`Sellers::find`] reports only the best match of each run: a position where
+/// the distance is smaller than on the previous and the next positions.
+/// If the distance stays the same on a few positions in a row, the last of them is reported.
+/// The start of each match is chosen to make it as short as possible.
+///
+/// [Sellers' algorithm]: https://doi.org/10.1016/0196-6774(80)90016-4
+/// [`Levenshtein`]: crate::Levenshtein
+pub struct Sellers {
+    /// The maximum number of errors in a match.
+    pub max_distance: usize,
+}
+
+impl Sellers {
+    /// Find all matches of the pattern in the text.
+    ///
+    /// The matches are sorted by their end position. An empty pattern matches nothing.
+    /// If the pattern isn't longer than `max_distance`, a match may be empty,
+    /// including the one before the first element of the text.
+    ///
+    ///     use textdistance::search::{Match, Sellers};
+    ///
+    ///     let s = Sellers { max_distance: 1 };
+    ///     let found = s.find(&[1, 2, 3], &[0, 1, 2, 3, 0, 1, 3, 0]);
+    ///     assert!(found == vec![
+    ///         Match { start: 1, end: 4, distance: 0 },
+    ///         Match { start: 5, end: 7, distance: 1 },
+    ///     ]);
+    ///
+    pub fn find<E: Eq>(&self, pattern: &[E], text: &[E]) -> Vec<Match> {
+        let mut runs = Runs::default();
+        self.scan(pattern, text, |m| runs.push(m));
+        runs.finish()
+    }
+
+    /// Find the match with the smallest distance.
+    ///
+    /// It's one of the matches returned by [`Sellers::find`].
+    /// On ties, the match that ends first is returned.
+    ///
+    ///     use textdistance::search::{Match, Sellers};
+    ///
+    ///     let s = Sellers { max_distance: 2 };
+    ///     let found = s.best(&[1, 2, 3], &[1, 0, 0, 1, 2, 0]);
+    ///     assert!(found == Some(Match { start: 3, end: 6, distance: 1 }));
+    ///
+    pub fn best<E: Eq>(&self, pattern: &[E], text: &[E]) -> Option<Match> {
+        let found = self.find(pattern, text);
+        found.into_iter().min_by_key(|m| m.distance)
+    }
+
+    /// Find all matches of the pattern in the text, see [`Sellers::find`].
+    ///
+    /// The strings are compared by characters, but the positions of matches
+    /// are byte offsets, so `&text[m.start..m.end]` is the matched part of the text.
+    pub fn find_str(&self, pattern: &str, text: &str) -> Vec<Match> {
+        on_chars(pattern, text, |pattern, chars, to_bytes| {
+            let found = self.find(pattern, chars);
+            found.into_iter().map(to_bytes).collect()
+        })
+    }
+
+    /// Find the best match of the pattern in the text, see [`Sellers::best`].
+    ///
+    /// The positions of the match are byte offsets, like in [`Sellers::find_str`].
+    pub fn best_str(&self, pattern: &str, text: &str) -> Option<Match> {
+        on_chars(pattern, text, |pattern, chars, to_bytes| {
+            self.best(pattern, chars).map(to_bytes)
+        })
+    }
+
+    /// Call the function for each position of the text, starting with the one before
+    /// the first element, with the best match that ends there, or with `None`
+    /// if there is no match ending there.
+    fn scan<E, F>(&self, pattern: &[E], text: &[E], mut f: F)
+    where
+        E: Eq,
+        F: FnMut(Option<Match>),
+    {
+        let m = pattern.len();
+        if m == 0 {
+            return;
+        }
+        let k = self.max_distance;
+        // distances above max_distance are all the same for the search
+        let over = k + 1;
+        // For the current position in the text, the distance between the first i
+        // elements of the pattern and the best part of the text ending there,
+        // and where that part starts.
+        let mut dist: Vec<usize> = (0..=m).map(|i| i.min(over)).collect();
+        let mut start = vec![0; m + 1];
+        // the last row that is within max_distance
+        let mut last = k.min(m);
+        f((last == m).then_some(Match {
+            start: 0,
+            end: 0,
+            distance: m,
+        }));
+        for (j, e) in text.iter().enumerate() {
+            // Rows after `last + 1` are not calculated. Their old values
+            // are above max_distance, and so are the new ones.
+            let limit = m.min(last + 1);
+            let mut diag = (dist[0], start[0]);
+            start[0] = j + 1;
+            for i in 1..=limit {
+                let up = (dist[i], start[i]);
+                let cost = usize::from(pattern[i - 1] != *e);
+                let mut cell = (diag.0 + cost, diag.1);
+                for (d, s) in [(up.0 + 1, up.1), (dist[i - 1] + 1, start[i - 1])] {
+                    // prefer shorter matches on ties
+                    if d < cell.0 || (d == cell.0 && s > cell.1) {
+                        cell = (d, s);
+                    }
+                }
+                diag = up;
+                dist[i] = cell.0.min(over);
+                start[i] = cell.1;
+            }
+            last = limit;
+            while dist[last] > k {
+                last -= 1;
+            }
+            let found = (last == m).then(|| Match {
+                start: start[m],
+                end: j + 1,
+                distance: dist[m],
+            });
+            f(found);
+        }
+    }
+}
+
+/// [Bitap] (shift-and) search for patterns of up to 64 elements with up to `max_distance` errors.
+///
+/// The state for each number of errors is a single 64-bit integer, and each element
+/// of the text is processed with a few bitwise operations (Wu and Manber's extension
+/// of the algorithm), so the search takes `O(max_distance * text length)` time
+/// no matter how similar the text is to the pattern. The text is never read twice,
+/// so [`BitapSearcher`] can process a stream one element at a time.
+///
+/// In Levenshtein mode (the default), the matches are the same as of [`Sellers`].
+/// In Hamming mode, only substitutions are counted as errors, so all matches have
+/// the same length as the pattern, and all of them are reported.
+///
+/// Patterns longer than 64 elements are supported by [`Bitap::find`] and [`Bitap::best`],
+/// but they are searched with [`Sellers`] (or a naive search in Hamming mode).
+///
+///     use textdistance::search::{Bitap, Match};
+///
+///     let b = Bitap { max_distance: 1, hamming: true };
+///     let found = b.find(b"ACGT", b"TTACCTGACGA");
+///     assert!(found == vec![
+///         Match { start: 2, end: 6, distance: 1 },
+///         Match { start: 7, end: 11, distance: 1 },
+///     ]);
+///
+/// [Bitap]: https://en.wikipedia.org/wiki/Bitap_algorithm
+#[derive(Default)]
+pub struct Bitap {
+    /// The maximum number of errors in a match.
+    /// Default: 0.
+    pub max_distance: usize,
+
+    /// If true, only substitutions are counted, like in [`Hamming`](crate::Hamming).
+    /// Otherwise, insertions and deletions are counted as well,
+    /// like in [`Levenshtein`](crate::Levenshtein).
+    /// Default: false.
+    pub hamming: bool,
+}
+
+impl Bitap {
+    /// Prepare the pattern for searching in a stream.
+    ///
+    /// Returns `None` if the pattern is empty or longer than 64 elements.
+    ///
+    ///     use textdistance::search::Bitap;
+    ///
+    ///     let b = Bitap { max_distance: 1, ..Default::default() };
+    ///     let mut s = b.searcher(&['a', 'b', 'c']).unwrap();
+    ///     let found: Vec<Option<usize>> = "xabxc".chars().map(|c| s.push(c)).collect();
+    ///     assert!(found == vec![None, None, Some(1), Some(1), Some(1)]);
+    ///
+    pub fn searcher<E: Ord + Copy>(&self, pattern: &[E]) -> Option<BitapSearcher<E>> {
+        let len = pattern.len();
+        if len == 0 || len > 64 {
+            return None;
+        }
+        let mut masks: Vec<(E, u64)> = Vec::new();
+        for (i, e) in pattern.iter().enumerate() {
+            match masks.binary_search_by(|(m, _)| m.cmp(e)) {
+                Ok(pos) => masks[pos].1 |= 1 << i,
+                Err(pos) => masks.insert(pos, (*e, 1 << i)),
+            }
+        }
+        let mut searcher = BitapSearcher {
+            masks,
+            last: 1 << (len - 1),
+            hamming: self.hamming,
+            state: vec![0; self.max_distance + 1],
+        };
+        searcher.reset();
+        Some(searcher)
+    }
+
+    /// Find all matches of the pattern in the text.
+    ///
+    /// In Levenshtein mode, the result is the same as of [`Sellers::find`].
+    /// In Hamming mode, all matches are reported.
+    pub fn find<E: Ord + Copy>(&self, pattern: &[E], text: &[E]) -> Vec<Match> {
+        let Some(mut searcher) = self.searcher(pattern) else {
+            if pattern.is_empty() {
+                return Vec::new();
+            }
+            if self.hamming {
+                return self.find_naive(pattern, text);
+            }
+            let sellers = Sellers {
+                max_distance: self.max_distance,
+            };
+            return sellers.find(pattern, text);
+        };
+        if self.hamming {
+            let mut found = Vec::new();
+            for (i, e) in text.iter().enumerate() {
+                if let Some(distance) = searcher.push(*e) {
+                    found.push(Match {
+                        start: i + 1 - pattern.len(),
+                        end: i + 1,
+                        distance,
+                    });
+                }
+            }
+            return found;
+        }
+        let mut runs = Runs::default();
+        // the empty pattern prefix matches the empty text, like in Sellers
+        runs.push((pattern.len() <= self.max_distance).then_some(Match {
+            start: 0,
+            end: 0,
+            distance: pattern.len(),
+        }));
+        for (i, e) in text.iter().enumerate() {
+            // the start is found later, only for the reported matches
+            let m = searcher.push(*e).map(|distance| Match {
+                start: i + 1,
+                end: i + 1,
+                distance,
+            });
+            runs.push(m);
+        }
+        let mut found = runs.finish();
+        for m in &mut found {
+            m.start = shortest_start(pattern, &text[..m.end], m.distance);
+        }
+        found
+    }
+
+    /// Find the match with the smallest distance.
+    ///
+    /// It's one of the matches returned by [`Bitap::find`].
+    /// On ties, the match that ends first is returned.
+    pub fn best<E: Ord + Copy>(&self, pattern: &[E], text: &[E]) -> Option<Match> {
+        let found = self.find(pattern, text);
+        found.into_iter().min_by_key(|m| m.distance)
+    }
+
+    /// Find all matches of the pattern in the text, see [`Bitap::find`].
+    ///
+    /// The strings are compared by characters, and the positions of matches are
+    /// byte offsets, like in [`Sellers::find_str`]. To compare bytes instead,
+    /// pass `as_bytes()` of both strings to [`Bitap::find`].
+    pub fn find_str(&self, pattern: &str, text: &str) -> Vec<Match> {
+        on_chars(pattern, text, |pattern, chars, to_bytes| {
+            let found = self.find(pattern, chars);
+            found.into_iter().map(to_bytes).collect()
+        })
+    }
+
+    /// Find the best match of the pattern in the text, see [`Bitap::best`].
+    ///
+    /// The positions of the match are byte offsets, like in [`Bitap::find_str`].
+    pub fn best_str(&self, pattern: &str, text: &str) -> Option<Match> {
+        on_chars(pattern, text, |pattern, chars, to_bytes| {
+            self.best(pattern, chars).map(to_bytes)
+        })
+    }
+
+    /// Compare the pattern with each part of the text of the same length.
+    fn find_naive<E: Eq>(&self, pattern: &[E], text: &[E]) -> Vec<Match> {
+        let mut found = Vec::new();
+        for (start, window) in text.windows(pattern.len()).enumerate() {
+            let distance = pattern.iter().zip(window).filter(|(a, b)| a != b).count();
+            if distance <= self.max_distance {
+                found.push(Match {
+                    start,
+                    end: start + pattern.len(),
+                    distance,
+                });
+            }
+        }
+        found
+    }
+}
+
+/// A pattern prepared by [`Bitap::searcher`] for searching in a stream of elements.
+pub struct BitapSearcher<E> {
+    /// For each element of the pattern, the bits of positions where it occurs.
+    masks: Vec<(E, u64)>,
+    /// The bit of the last position of the pattern.
+    last: u64,
+    hamming: bool,
+    /// For each number of errors `d`, the bit `i` is set if the first `i + 1` elements
+    /// of the pattern match the end of the text read so far with up to `d` errors.
+    state: Vec<u64>,
+}
+
+impl<E: Ord + Copy> BitapSearcher<E> {
+    /// Read the next element of the text.
+    ///
+    /// Returns the smallest number of errors with which the pattern matches
+    /// the text ending with this element, or `None` if there are too many errors.
+    pub fn push(&mut self, e: E) -> Option<usize> {
+        let mask = match self.masks.binary_search_by(|(m, _)| m.cmp(&e)) {
+            Ok(pos) => self.masks[pos].1,
+            Err(_) => 0,
+        };
+        let mut prev_old = 0;
+        let mut prev_new = 0;
+        for (d, r) in self.state.iter_mut().enumerate() {
+            let old = *r;
+            let mut new = (old << 1 | 1) & mask;
+            if d > 0 {
+                // substitution
+                new |= prev_old << 1 | 1;
+                if !self.hamming {
+                    // insertion into the pattern and deletion from it
+                    new |= prev_old | prev_new << 1;
+                }
+            }
+            *r = new;
+            prev_old = old;
+            prev_new = new;
+        }
+        self.state.iter().position(|r| r & self.last != 0)
+    }
+
+    /// Forget the text read so far.
+    pub fn reset(&mut self) {
+        for (d, r) in self.state.iter_mut().enumerate() {
+            // in Levenshtein mode, the first d elements of the pattern
+            // match an empty text with d deletions
+            *r = match d {
+                _ if self.hamming => 0,
+                64.. => u64::MAX,
+                _ => (1 << d) - 1,
+            };
+        }
+    }
+}
+
+/// The start of the shortest part at the end of the text that is
+/// within the distance from the pattern.
+fn shortest_start<E: Eq>(pattern: &[E], text: &[E], distance: usize) -> usize {
+    let m = pattern.len();
+    // Distances between the last i elements of the pattern
+    // and the last elements of the text, growing to the left.
+    let mut col: Vec<usize> = (0..=m).collect();
+    if col[m] <= distance {
+        return text.len();
+    }
+    for (l, e) in text.iter().rev().enumerate() {
+        let mut diag = col[0];
+        col[0] = l + 1;
+        for i in 1..=m {
+            let up = col[i];
+            let cost = usize::from(pattern[m - i] != *e);
+            col[i] = (diag + cost).min(up + 1).min(col[i - 1] + 1);
+            diag = up;
+        }
+        if col[m] <= distance {
+            return text.len() - l - 1;
+        }
+    }
+    0
+}
+
+/// Picks the best match from each run of matches at neighboring positions,
+/// see [`Sellers`].
+#[derive(Default)]
+struct Runs {
+    found: Vec<Match>,
+    prev: Option<usize>,
+    best: Option<Match>,
+}
+
+impl Runs {
+    /// Add the match ending at the next position of the text, if any.
+    fn push(&mut self, m: Option<Match>) {
+        let Some(m) = m else {
+            self.found.extend(self.best.take());
+            self.prev = None;
+            return;
+        };
+        match self.best {
+            Some(b) if m.distance <= b.distance => self.best = Some(m),
+            Some(_) => self.found.extend(self.best.take()),
+            // start a new run only when the distance goes down
+            None if self.prev.is_none_or(|p| m.distance < p) => self.best = Some(m),
+            None => {}
+        }
+        self.prev = Some(m.distance);
+    }
+
+    fn finish(mut self) -> Vec<Match> {
+        self.found.extend(self.best);
+        self.found
+    }
+}
+
+/// Call the function with the characters of the pattern and the text
+/// and with a conversion of match positions from character indices to byte offsets.
+fn on_chars<T, F>(pattern: &str, text: &str, f: F) -> T
+where
+    F: FnOnce(&[char], &[char], &dyn Fn(Match) -> Match) -> T,
+{
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (offsets, chars): (Vec<usize>, Vec<char>) = text.char_indices().unzip();
+    let offset = |i: usize| offsets.get(i).copied().unwrap_or(text.len());
+    let to_bytes = |m: Match| Match {
+        start: offset(m.start),
+        end: offset(m.end),
+        distance: m.distance,
+    };
+    f(&pattern, &chars, &to_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::str::levenshtein;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn m(start: usize, end: usize, distance: usize) -> Match {
+        Match {
+            start,
+            end,
+            distance,
+        }
+    }
+
+    #[rstest]
+    #[case("", "abc", 1, vec![])]
+    #[case("abc", "", 1, vec![])]
+    #[case("a", "", 1, vec![m(0, 0, 1)])]
+    #[case("ab", "", 2, vec![m(0, 0, 2)])]
+    #[case("abc", "abc", 0, vec![m(0, 3, 0)])]
+    #[case("abc", "xxabcxx", 0, vec![m(2, 5, 0)])]
+    #[case("abc", "xxabcxx", 2, vec![m(2, 5, 0)])]
+    #[case("abc", "abcabc", 1, vec![m(0, 3, 0), m(3, 6, 0)])]
+    #[case("abc", "axc", 0, vec![])]
+    #[case("abc", "axc", 1, vec![m(0, 3, 1)])]
+    #[case("abc", "ac", 1, vec![m(0, 2, 1)])]
+    #[case("abc", "abxc", 1, vec![m(0, 4, 1)])]
+    #[case("abc", "xbcx", 1, vec![m(1, 3, 1)])]
+    #[case("survey", "the surgery was", 2, vec![m(4, 11, 2)])]
+    #[case("brown", "the quick brwn fox", 1, vec![m(10, 14, 1)])]
+    #[case("ж", "абвжз", 0, vec![m(6, 8, 0)])]
+    fn find(
+        #[case] pattern: &str,
+        #[case] text: &str,
+        #[case] max_distance: usize,
+        #[case] exp: Vec<Match>,
+    ) {
+        let s = Sellers { max_distance };
+        assert!(s.find_str(pattern, text) == exp);
+    }
+
+    #[rstest]
+    #[case("abc", "", 1, None)]
+    #[case("abc", "xyz", 2, None)]
+    #[case("abc", "xbc abx", 2, Some(m(1, 3, 1)))]
+    #[case("abc", "xbc abc", 2, Some(m(4, 7, 0)))]
+    fn best(
+        #[case] pattern: &str,
+        #[case] text: &str,
+        #[case] max_distance: usize,
+        #[case] exp: Option<Match>,
+    ) {
+        let s = Sellers { max_distance };
+        assert!(s.best_str(pattern, text) == exp);
+    }
+
+    #[rstest]
+    #[case("abc", "xxabcxx", 0, false, vec![m(2, 5, 0)])]
+    #[case("abc", "abxc", 1, false, vec![m(0, 4, 1)])]
+    #[case("abc", "abxc", 1, true, vec![m(0, 3, 1)])]
+    #[case("aaa", "aaaa", 0, true, vec![m(0, 3, 0), m(1, 4, 0)])]
+    #[case("aaa", "aaaa", 0, false, vec![m(1, 4, 0)])]
+    #[case("survey", "the surgery was", 2, false, vec![m(4, 11, 2)])]
+    #[case("survey", "the surgery was", 2, true, vec![m(4, 10, 2)])]
+    #[case("ж", "абвжз", 0, false, vec![m(6, 8, 0)])]
+    fn bitap(
+        #[case] pattern: &str,
+        #[case] text: &str,
+        #[case] max_distance: usize,
+        #[case] hamming: bool,
+        #[case] exp: Vec<Match>,
+    ) {
+        let b = Bitap {
+            max_distance,
+            hamming,
+        };
+        assert!(b.find_str(pattern, text) == exp);
+    }
+
+    #[test]
+    fn bitap_long() {
+        let pattern: Vec<u32> = (0..100).collect();
+        let mut text: Vec<u32> = (0..300).map(|i| i % 150).collect();
+        text[60] = 1000;
+        for hamming in [false, true] {
+            let b = Bitap {
+                max_distance: 1,
+                hamming,
+            };
+            assert!(b.searcher(&pattern).is_none());
+            assert!(b.best(&pattern, &text) == Some(m(150, 250, 0)));
+            assert!(b.searcher(&pattern[..64]).is_some());
+            assert!(b.best(&pattern[..64], &text) == Some(m(150, 214, 0)));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_find(pattern in "[abc]{1,5}", text in "[abc]{0,30}", max_distance in 0usize..4) {
+            let s = Sellers { max_distance };
+            let found = s.find_str(&pattern, &text);
+            for w in found.windows(2) {
+                prop_assert!(w[0].end < w[1].end);
+            }
+            for f in &found {
+                prop_assert!(f.distance <= max_distance);
+                prop_assert!(levenshtein(&pattern, &text[f.start..f.end]) == f.distance);
+            }
+        }
+
+        #[test]
+        fn prop_best(pattern in "[abc]{1,5}", text in "[abc]{0,20}", max_distance in 0usize..4) {
+            let s = Sellers { max_distance };
+            let mut exp: Option<usize> = None;
+            for end in 0..=text.len() {
+                for start in 0..=end {
+                    let d = levenshtein(&pattern, &text[start..end]);
+                    if d <= max_distance && exp.is_none_or(|e| d < e) {
+                        exp = Some(d);
+                    }
+                }
+            }
+            let best = s.best_str(&pattern, &text);
+            prop_assert!(best.map(|b| b.distance) == exp);
+            if let Some(b) = best {
+                prop_assert!(s.find_str(&pattern, &text).contains(&b));
+            }
+        }
+
+        #[test]
+        fn prop_bitap(pattern in "[abc]{1,5}", text in "[abc]{0,30}", max_distance in 0usize..4) {
+            let b = Bitap { max_distance, hamming: false };
+            let sellers = Sellers { max_distance };
+            prop_assert!(b.find_str(&pattern, &text) == sellers.find_str(&pattern, &text));
+            prop_assert!(b.find(pattern.as_bytes(), text.as_bytes()) == sellers.find_str(&pattern, &text));
+        }
+
+        #[test]
+        fn prop_bitap_hamming(pattern in "[abc]{1,5}", text in "[abc]{0,30}", max_distance in 0usize..4) {
+            let b = Bitap { max_distance, hamming: true };
+            prop_assert!(b.find_str(&pattern, &text) == b.find_naive(pattern.as_bytes(), text.as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,393 @@
+//! Spelling correction with a symmetric delete index.
+//!
+//! [`SymSpell`] finds dictionary words close to a misspelled query without comparing
+//! the query to every word. It can also split a text without spaces (or with misplaced
+//! spaces) into words, correcting each of them.
+//!
+//!     use textdistance::spelling::SymSpell;
+//!
+//!     let mut index = SymSpell::new(2);
+//!     index.extend([("hello", 100), ("help", 50), ("world", 80)]);
+//!
+//!     let found = index.lookup("helo", 2);
+//!     assert!(found[0].word == "hello");
+//!     assert!(found[0].distance == 1);
+//!
+//!     let text = index.lookup_compound("helloworld", 2);
+//!     assert!(text.words == vec!["hello", "world"]);
+//!
+#![cfg(feature = "std")]
+use crate::{Algorithm, DamerauLevenshtein};
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::collections::{HashMap, HashSet};
+
+/// A word suggested by [`SymSpell::lookup`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Suggestion<'a> {
+    /// The dictionary word.
+    pub word: &'a str,
+
+    /// The distance between the query and the word.
+    pub distance: usize,
+
+    /// How often the word occurs, as added to the index.
+    pub count: u64,
+}
+
+/// A text split into words by [`SymSpell::lookup_compound`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Segmentation {
+    /// The corrected words.
+    pub words: Vec<String>,
+
+    /// The sum of distances between the parts of the text and the words.
+    pub distance: usize,
+
+    /// The sum of base 10 logarithms of the probabilities of the words.
+    pub log_prob: f64,
+}
+
+/// [SymSpell] is an index for spelling correction based on symmetric deletes.
+///
+/// For each dictionary word, all strings that can be made by deleting up to
+/// `max_distance` characters are precomputed. A query is looked up by generating
+/// the same deletes for it: if a word is within the distance, they share a delete.
+/// The candidates found this way are then verified with [`DamerauLevenshtein`]
+/// in the restricted variant (optimal string alignment).
+///
+/// Lookups are fast no matter how big the dictionary is, but the index takes
+/// a lot of memory: about `length ^ max_distance` deletes for each word.
+/// `max_distance` of 1 or 2 is usually enough.
+///
+/// [SymSpell]: https://github.com/wolfgarbe/SymSpell
+pub struct SymSpell {
+    max_distance: usize,
+    /// Dictionary words with their counts.
+    words: Vec<(String, u64)>,
+    ids: HashMap<String, usize>,
+    /// For each delete, the ids of the words that produce it.
+    deletes: HashMap<String, Vec<usize>>,
+    /// The length of the longest word, in characters.
+    max_len: usize,
+    /// The sum of all counts.
+    total: u64,
+}
+
+impl SymSpell {
+    /// Create an empty index for lookups within `max_distance`.
+    pub fn new(max_distance: usize) -> Self {
+        Self {
+            max_distance,
+            words: Vec::new(),
+            ids: HashMap::new(),
+            deletes: HashMap::new(),
+            max_len: 0,
+            total: 0,
+        }
+    }
+
+    /// The maximum distance supported by the index.
+    pub fn max_distance(&self) -> usize {
+        self.max_distance
+    }
+
+    /// The number of distinct words in the index.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Check if the index has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// How often the word occurs, or 0 if it isn't in the index.
+    pub fn count(&self, word: &str) -> u64 {
+        self.ids.get(word).map_or(0, |id| self.words[*id].1)
+    }
+
+    /// Add the word with the number of its occurrences in some corpus.
+    ///
+    /// The counts are used to rank suggestions with the same distance.
+    /// If the word is already in the index, the count is added to the old one.
+    pub fn add(&mut self, word: &str, count: u64) {
+        self.total += count;
+        if let Some(id) = self.ids.get(word) {
+            self.words[*id].1 += count;
+            return;
+        }
+        let id = self.words.len();
+        self.words.push((word.to_string(), count));
+        self.ids.insert(word.to_string(), id);
+        self.max_len = self.max_len.max(word.chars().count());
+        for delete in deletes(word, self.max_distance) {
+            self.deletes.entry(delete).or_default().push(id);
+        }
+    }
+
+    /// Find all words within `max_distance` from the query.
+    ///
+    /// The suggestions are sorted by the distance, then by the count (most frequent first),
+    /// then alphabetically. The distance can't be more than the `max_distance` of the index.
+    ///
+    ///     use textdistance::spelling::SymSpell;
+    ///
+    ///     let mut index = SymSpell::new(2);
+    ///     index.extend([("bank", 30), ("band", 20), ("bark", 10), ("brand", 40)]);
+    ///     let found: Vec<&str> = index.lookup("bnak", 1).iter().map(|s| s.word).collect();
+    ///     assert!(found == vec!["bank"]);
+    ///     let found: Vec<&str> = index.lookup("bnak", 2).iter().map(|s| s.word).collect();
+    ///     assert!(found == vec!["bank", "band", "bark"]);
+    ///
+    pub fn lookup(&self, query: &str, max_distance: usize) -> Vec<Suggestion<'_>> {
+        let max_distance = max_distance.min(self.max_distance);
+        let len = query.chars().count();
+        if len > self.max_len + max_distance {
+            return Vec::new();
+        }
+        let a = DamerauLevenshtein {
+            restricted: true,
+            ..Default::default()
+        };
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut found = Vec::new();
+        for delete in deletes(query, max_distance) {
+            let Some(ids) = self.deletes.get(&delete) else {
+                continue;
+            };
+            for id in ids {
+                if !seen.insert(*id) {
+                    continue;
+                }
+                let (word, count) = &self.words[*id];
+                if word.chars().count().abs_diff(len) > max_distance {
+                    continue;
+                }
+                let distance = a.for_str(query, word).val();
+                if distance <= max_distance {
+                    found.push(Suggestion {
+                        word,
+                        distance,
+                        count: *count,
+                    });
+                }
+            }
+        }
+        found.sort_by(|s1, s2| {
+            s1.distance
+                .cmp(&s2.distance)
+                .then(s2.count.cmp(&s1.count))
+                .then(s1.word.cmp(s2.word))
+        });
+        found
+    }
+
+    /// Split the text into words and correct each of them.
+    ///
+    /// Whitespace in the text is ignored, so it works for texts without spaces
+    /// as well as for texts with missing or extra spaces. Each part of the text is
+    /// replaced by the best [`lookup`](Self::lookup) suggestion within `max_distance`
+    /// or kept as is if there is none. A kept part counts as one error per character.
+    /// The segmentation with the smallest total distance is chosen. On ties, the one
+    /// that keeps more characters as is, so that unknown parts aren't absorbed into
+    /// the corrections of their neighbours, and then the one with the most probable words
+    /// (based on their counts).
+    ///
+    ///     use textdistance::spelling::SymSpell;
+    ///
+    ///     let mut index = SymSpell::new(1);
+    ///     index.extend([("the", 100), ("quick", 20), ("brown", 30), ("fox", 10)]);
+    ///     let text = index.lookup_compound("thequikbrown fo x", 1);
+    ///     assert!(text.words == vec!["the", "quick", "brown", "fox"]);
+    ///     assert!(text.distance == 1);
+    ///
+    pub fn lookup_compound(&self, text: &str, max_distance: usize) -> Segmentation {
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let max_len = self.max_len + max_distance.min(self.max_distance);
+        let total = self.total.max(1) as f64;
+        // The best segmentation of the first i characters: the total distance,
+        // how many characters are kept, the probability, where the last word starts, and the word.
+        let mut best: Vec<(usize, usize, f64, usize, String)> = Vec::with_capacity(chars.len() + 1);
+        best.push((0, 0, 0., 0, String::new()));
+        for end in 1..=chars.len() {
+            let mut cell: Option<(usize, usize, f64, usize, String)> = None;
+            for start in end.saturating_sub(max_len.max(1))..end {
+                let part: String = chars[start..end].iter().collect();
+                let found = self.lookup(&part, max_distance);
+                // a correction that replaces the whole part is no better than an unknown word
+                let found = found.first().filter(|s| s.distance < end - start);
+                let (word, distance, kept, log_prob) = if let Some(s) = found {
+                    let log_prob = (s.count.max(1) as f64 / total).log10();
+                    (s.word.to_string(), s.distance, 0, log_prob)
+                } else {
+                    // unknown words are the less likely the longer they are
+                    let len = (end - start) as f64;
+                    let log_prob = (10. / total).log10() - len;
+                    (part, end - start, end - start, log_prob)
+                };
+                let distance = best[start].0 + distance;
+                let kept = best[start].1 + kept;
+                let log_prob = best[start].2 + log_prob;
+                // smaller distance, then more kept characters, then higher probability
+                let better = match &cell {
+                    None => true,
+                    Some(c) => (c.0, kept, log_prob) > (distance, c.1, c.2),
+                };
+                if better {
+                    cell = Some((distance, kept, log_prob, start, word));
+                }
+            }
+            best.extend(cell);
+        }
+
+        let mut words = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let (_, _, _, start, word) = &best[end];
+            words.push(word.clone());
+            end = *start;
+        }
+        words.reverse();
+        let (distance, _, log_prob, _, _) = best[chars.len()];
+        Segmentation {
+            words,
+            distance,
+            log_prob,
+        }
+    }
+}
+
+impl<'a> Extend<(&'a str, u64)> for SymSpell {
+    fn extend<I: IntoIterator<Item = (&'a str, u64)>>(&mut self, iter: I) {
+        for (word, count) in iter {
+            self.add(word, count);
+        }
+    }
+}
+
+/// All strings made by deleting up to `max_distance` characters from the word,
+/// including the word itself.
+fn deletes(word: &str, max_distance: usize) -> HashSet<String> {
+    let mut found: HashSet<String> = HashSet::new();
+    found.insert(word.to_string());
+    let mut level = vec![word.to_string()];
+    for _ in 0..max_distance {
+        let mut next = Vec::new();
+        for s in &level {
+            for (i, c) in s.char_indices() {
+                let mut delete = String::with_capacity(s.len());
+                delete.push_str(&s[..i]);
+                delete.push_str(&s[i + c.len_utf8()..]);
+                // strings found on earlier levels are already expanded
+                if found.insert(delete.clone()) {
+                    next.push(delete);
+                }
+            }
+        }
+        level = next;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert2::assert;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn index() -> SymSpell {
+        let mut index = SymSpell::new(2);
+        index.extend([
+            ("the", 1000),
+            ("quick", 50),
+            ("brown", 60),
+            ("fox", 40),
+            ("jumps", 30),
+            ("over", 200),
+            ("lazy", 20),
+            ("dog", 80),
+            ("a", 900),
+            ("an", 500),
+        ]);
+        index
+    }
+
+    #[rstest]
+    #[case("", 0, vec![])]
+    #[case("the", 0, vec![("the", 0)])]
+    #[case("teh", 1, vec![("the", 1)])]
+    #[case("dgo", 1, vec![("dog", 1)])]
+    #[case("fx", 1, vec![("fox", 1)])]
+    #[case("quikc", 1, vec![("quick", 1)])]
+    #[case("xyz", 2, vec![])]
+    #[case("ab", 1, vec![("a", 1), ("an", 1)])]
+    #[case("fo", 2, vec![("fox", 1), ("a", 2), ("an", 2), ("dog", 2)])]
+    #[case("brownish", 2, vec![])]
+    fn lookup(#[case] query: &str, #[case] max_distance: usize, #[case] exp: Vec<(&str, usize)>) {
+        let index = index();
+        let found: Vec<(&str, usize)> = index
+            .lookup(query, max_distance)
+            .iter()
+            .map(|s| (s.word, s.distance))
+            .collect();
+        assert!(found == exp);
+    }
+
+    #[test]
+    fn counts() {
+        let mut index = SymSpell::new(1);
+        index.add("word", 2);
+        index.add("word", 3);
+        index.add("ward", 4);
+        assert!(index.len() == 2);
+        assert!(index.count("word") == 5);
+        assert!(index.count("wird") == 0);
+        let found = index.lookup("wurd", 1);
+        assert!(found[0].word == "word");
+        assert!(found[0].count == 5);
+        assert!(found[1].word == "ward");
+    }
+
+    #[rstest]
+    #[case("", vec![], 0)]
+    #[case("thequickbrownfox", vec!["the", "quick", "brown", "fox"], 0)]
+    #[case("the quick brownfox", vec!["the", "quick", "brown", "fox"], 0)]
+    #[case("thequikbrwnfox", vec!["the", "quick", "brown", "fox"], 2)]
+    #[case("th equick", vec!["the", "quick"], 0)]
+    #[case("xyzzy", vec!["xyzzy"], 5)]
+    // unknown characters are kept instead of being absorbed by known words
+    #[case("thezzzdog", vec!["the", "zzz", "dog"], 3)]
+    #[case("thezdog", vec!["the", "z", "dog"], 1)]
+    fn lookup_compound(#[case] text: &str, #[case] exp: Vec<&str>, #[case] distance: usize) {
+        let res = index().lookup_compound(text, 1);
+        assert!(res.words == exp);
+        assert!(res.distance == distance);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_lookup(
+            words in prop::collection::vec(("[abc]{0,6}", 1u64..100), 0..20),
+            query in "[abcd]{0,7}",
+            max_distance in 0usize..3,
+        ) {
+            let mut index = SymSpell::new(2);
+            for (word, count) in &words {
+                index.add(word, *count);
+            }
+            let a = DamerauLevenshtein { restricted: true, ..Default::default() };
+            let mut exp: Vec<&str> = index
+                .words
+                .iter()
+                .map(|(w, _)| w.as_str())
+                .filter(|w| a.for_str(&query, w).val() <= max_distance)
+                .collect();
+            exp.sort_unstable();
+            let mut found: Vec<&str> = index.lookup(&query, max_distance).iter().map(|s| s.word).collect();
+            found.sort_unstable();
+            prop_assert!(found == exp);
+        }
+    }
+}
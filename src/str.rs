@@ -34,6 +34,30 @@ pub fn damerau_levenshtein_restricted(s1: &str, s2: &str) -> usize {
     a.for_str(s1, s2).val()
 }
 
+/// Calculate [Editex distance][1] for two strings.
+///
+/// A wrapper for [`Editex`].
+///
+///     use textdistance::str::editex;
+///     assert!(editex("cat", "hat") == 2); // "c" and "h" are from different groups
+///
+/// [1]: https://anlp.org/wp-content/uploads/1996/01/Zobel-Dart-1996.pdf
+pub fn editex(s1: &str, s2: &str) -> usize {
+    Editex::default().for_str(s1, s2).val()
+}
+
+/// Calculate [Eudex][1] distance (the number of different bits in phonetic hashes) for two strings.
+///
+/// A wrapper for [`Eudex`].
+///
+///     use textdistance::str::eudex;
+///     assert!(eudex("maier", "meyer") == 0);
+///
+/// [1]: https://github.com/ticki/eudex
+pub fn eudex(s1: &str, s2: &str) -> usize {
+    Eudex::default().for_str(s1, s2).val()
+}
+
 /// Calculate [Hamming distance][1] for two strings.
 ///
 /// A wrapper for [`Hamming`].
@@ -94,6 +118,18 @@ pub fn ratcliff_obershelp(s1: &str, s2: &str) -> f64 {
     RatcliffObershelp::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Match Rating Approach][1] similarity rating (from 0 to 6) for two strings.
+///
+/// A wrapper for [`MRA`].
+///
+///     use textdistance::str::mra;
+///     assert!(mra("Smith", "Smyth") == 5); // SMTH and SMYTH
+///
+/// [1]: https://en.wikipedia.org/wiki/Match_rating_approach
+pub fn mra(s1: &str, s2: &str) -> usize {
+    MRA::default().for_str(s1, s2).val()
+}
+
 /// Calculate [Sift4 distance][1] for two strings using the "simplest" algorithm.
 ///
 /// A wrapper for [`Sift4Simple`].
@@ -142,6 +178,18 @@ pub fn jaro_winkler(s1: &str, s2: &str) -> f64 {
     JaroWinkler::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Strcmp95 similarity][1] for two strings.
+///
+/// A wrapper for [`Strcmp95`].
+///
+///     use textdistance::str::strcmp95;
+///     assert!(strcmp95("martha", "marhta") > 0.96);
+///
+/// [1]: https://web.archive.org/web/20100227020019/http://www.census.gov/geo/msb/stand/strcmp.c
+pub fn strcmp95(s1: &str, s2: &str) -> f64 {
+    Strcmp95::default().for_str(s1, s2).nval()
+}
+
 /// Calculate [Yujian-Bo normalization][1] of [Levenshtein] for two strings.
 ///
 /// A wrapper for [`YujianBo`].
@@ -179,6 +227,19 @@ pub fn bag(s1: &str, s2: &str) -> usize {
     Bag::default().for_str(s1, s2).val()
 }
 
+/// Calculate [Q-gram distance][1] for two strings.
+///
+/// A wrapper for [`QGram`].
+///
+///     use textdistance::str::qgram;
+///     assert!(qgram("abc", "acbd") == 7);
+///
+/// [1]: https://doi.org/10.1016/0304-3975(92)90143-4
+#[cfg(feature = "std")]
+pub fn qgram(s1: &str, s2: &str) -> usize {
+    QGram::default().for_str(s1, s2).val()
+}
+
 /// Calculate [LIG3 normalization][1] of [Hamming] by [Levenshtein] for two strings.
 ///
 /// A wrapper for [`LIG3`].
@@ -204,6 +265,19 @@ pub fn jaccard(s1: &str, s2: &str) -> f64 {
     Jaccard::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Jaccard similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Jaccard`] with `as_set` enabled.
+///
+///     use textdistance::str::jaccard_set;
+///     assert_eq!(jaccard_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Jaccard_index
+#[cfg(feature = "std")]
+pub fn jaccard_set(s1: &str, s2: &str) -> f64 {
+    Jaccard { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate [Sørensen–Dice normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`SorensenDice`].
@@ -217,6 +291,19 @@ pub fn sorensen_dice(s1: &str, s2: &str) -> f64 {
     SorensenDice::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Sørensen–Dice similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`SorensenDice`] with `as_set` enabled.
+///
+///     use textdistance::str::sorensen_dice_set;
+///     assert_eq!(sorensen_dice_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient
+#[cfg(feature = "std")]
+pub fn sorensen_dice_set(s1: &str, s2: &str) -> f64 {
+    SorensenDice { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate [Tversky normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Tversky`].
@@ -230,6 +317,23 @@ pub fn tversky(s1: &str, s2: &str) -> f64 {
     Tversky::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Tversky similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Tversky`] with `as_set` enabled.
+///
+///     use textdistance::str::tversky_set;
+///     assert_eq!(tversky_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Tversky_index
+#[cfg(feature = "std")]
+pub fn tversky_set(s1: &str, s2: &str) -> f64 {
+    let a = Tversky {
+        as_set: true,
+        ..Default::default()
+    };
+    a.for_str(s1, s2).nval()
+}
+
 /// Calculate [Overlap normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Overlap`].
@@ -243,6 +347,19 @@ pub fn overlap(s1: &str, s2: &str) -> f64 {
     Overlap::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Overlap similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Overlap`] with `as_set` enabled.
+///
+///     use textdistance::str::overlap_set;
+///     assert_eq!(overlap_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Overlap_coefficient
+#[cfg(feature = "std")]
+pub fn overlap_set(s1: &str, s2: &str) -> f64 {
+    Overlap { as_set: true }.for_str(s1, s2).nval()
+}
+
 /// Calculate [Cosine normalized similarity][1] for two strings.
 ///
 /// A wrapper for [`Cosine`].
@@ -256,6 +373,267 @@ pub fn cosine(s1: &str, s2: &str) -> f64 {
     Cosine::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [Cosine similarity][1] for two strings compared as sets of characters.
+///
+/// A wrapper for [`Cosine`] with `as_set` enabled.
+///
+///     use textdistance::str::cosine_set;
+///     assert_eq!(cosine_set("aab", "abbb"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Cosine_similarity
+#[cfg(feature = "std")]
+pub fn cosine_set(s1: &str, s2: &str) -> f64 {
+    Cosine { as_set: true }.for_str(s1, s2).nval()
+}
+
+/// Calculate [Manhattan distance][1] for two strings.
+///
+/// A wrapper for [`Manhattan`].
+///
+///     use textdistance::str::manhattan;
+///     assert_eq!(manhattan("abc", "acbd"), 1. / 7.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Taxicab_geometry
+#[cfg(feature = "std")]
+pub fn manhattan(s1: &str, s2: &str) -> f64 {
+    Manhattan::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Euclidean distance][1] for two strings.
+///
+/// A wrapper for [`Euclidean`].
+///
+///     use textdistance::str::euclidean;
+///     assert_eq!(euclidean("abc", "acbd"), 0.3779644730092272);
+///
+/// [1]: https://en.wikipedia.org/wiki/Euclidean_distance
+#[cfg(feature = "std")]
+pub fn euclidean(s1: &str, s2: &str) -> f64 {
+    Euclidean::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Chebyshev distance][1] for two strings.
+///
+/// A wrapper for [`Chebyshev`].
+///
+///     use textdistance::str::chebyshev;
+///     assert_eq!(chebyshev("abc", "acbd"), 1.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Chebyshev_distance
+#[cfg(feature = "std")]
+pub fn chebyshev(s1: &str, s2: &str) -> f64 {
+    Chebyshev::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Minkowski distance][1] for two strings.
+///
+/// A wrapper for [`Minkowski`].
+///
+///     use textdistance::str::minkowski;
+///     assert_eq!(minkowski("abc", "acbd"), 0.3779644730092272);
+///
+/// [1]: https://en.wikipedia.org/wiki/Minkowski_distance
+#[cfg(feature = "std")]
+pub fn minkowski(s1: &str, s2: &str) -> f64 {
+    Minkowski::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Jensen-Shannon divergence][1] for two strings.
+///
+/// A wrapper for [`JensenShannon`].
+///
+///     use textdistance::str::jensen_shannon;
+///     assert_eq!(jensen_shannon("abc", "acbd"), 0.13792538097003013);
+///
+/// [1]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+#[cfg(feature = "std")]
+pub fn jensen_shannon(s1: &str, s2: &str) -> f64 {
+    JensenShannon::default().for_str(s1, s2).nval()
+}
+
+/// Calculate smoothed [Kullback-Leibler divergence][1] for two strings.
+///
+/// A wrapper for [`KullbackLeibler`].
+///
+///     use textdistance::str::kullback_leibler;
+///     assert_eq!(kullback_leibler("abc", "acbd"), 0.06989735497174632);
+///
+/// [1]: https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence
+#[cfg(feature = "std")]
+pub fn kullback_leibler(s1: &str, s2: &str) -> f64 {
+    KullbackLeibler::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Hellinger distance][1] for two strings.
+///
+/// A wrapper for [`Hellinger`].
+///
+///     use textdistance::str::hellinger;
+///     assert_eq!(hellinger("abc", "acbd"), 0.36602540378443854);
+///
+/// [1]: https://en.wikipedia.org/wiki/Hellinger_distance
+#[cfg(feature = "std")]
+pub fn hellinger(s1: &str, s2: &str) -> f64 {
+    Hellinger::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Bhattacharyya coefficient][1] for two strings.
+///
+/// A wrapper for [`Bhattacharyya`].
+///
+///     use textdistance::str::bhattacharyya;
+///     assert_eq!(bhattacharyya("abc", "acbd"), 0.8660254037844387);
+///
+/// [1]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+#[cfg(feature = "std")]
+pub fn bhattacharyya(s1: &str, s2: &str) -> f64 {
+    Bhattacharyya::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Ochiai coefficient][1] for two strings.
+///
+/// A wrapper for [`Ochiai`].
+///
+///     use textdistance::str::ochiai;
+///     assert_eq!(ochiai("abc", "acbd"), 0.8660254037844387);
+///
+/// [1]: https://en.wikipedia.org/wiki/Cosine_similarity#Otsuka%E2%80%93Ochiai_coefficient
+#[cfg(feature = "std")]
+pub fn ochiai(s1: &str, s2: &str) -> f64 {
+    Ochiai::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Kulczynski similarity][1] for two strings.
+///
+/// A wrapper for [`Kulczynski`].
+///
+///     use textdistance::str::kulczynski;
+///     assert_eq!(kulczynski("abc", "acbd"), 0.875);
+///
+/// [1]: https://doi.org/10.1002/9781118445112.stat07933
+#[cfg(feature = "std")]
+pub fn kulczynski(s1: &str, s2: &str) -> f64 {
+    Kulczynski::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Simpson similarity][1] for two strings.
+///
+/// A wrapper for [`Simpson`].
+///
+///     use textdistance::str::simpson;
+///     assert_eq!(simpson("abc", "acbd"), 1.);
+///
+/// [1]: https://en.wikipedia.org/wiki/Overlap_coefficient
+#[cfg(feature = "std")]
+pub fn simpson(s1: &str, s2: &str) -> f64 {
+    Simpson::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Braun-Blanquet similarity][1] for two strings.
+///
+/// A wrapper for [`BraunBlanquet`].
+///
+///     use textdistance::str::braun_blanquet;
+///     assert_eq!(braun_blanquet("abc", "acbd"), 0.75);
+///
+/// [1]: https://doi.org/10.1002/9781118445112.stat07933
+#[cfg(feature = "std")]
+pub fn braun_blanquet(s1: &str, s2: &str) -> f64 {
+    BraunBlanquet::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Bray-Curtis dissimilarity][1] for two strings.
+///
+/// A wrapper for [`BrayCurtis`].
+///
+///     use textdistance::str::bray_curtis;
+///     assert_eq!(bray_curtis("abc", "acbd"), 0.14285714285714285);
+///
+/// [1]: https://en.wikipedia.org/wiki/Bray%E2%80%93Curtis_dissimilarity
+#[cfg(feature = "std")]
+pub fn bray_curtis(s1: &str, s2: &str) -> f64 {
+    BrayCurtis::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Canberra distance][1] for two strings.
+///
+/// A wrapper for [`Canberra`].
+///
+///     use textdistance::str::canberra;
+///     assert_eq!(canberra("abc", "acbd"), 0.25);
+///
+/// [1]: https://en.wikipedia.org/wiki/Canberra_distance
+#[cfg(feature = "std")]
+pub fn canberra(s1: &str, s2: &str) -> f64 {
+    Canberra::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Sokal-Michener similarity][1] for two strings.
+///
+/// A wrapper for [`SokalMichener`].
+///
+///     use textdistance::str::sokal_michener;
+///     assert_eq!(sokal_michener("abc", "acbd"), 0.75);
+///
+/// [1]: https://en.wikipedia.org/wiki/Simple_matching_coefficient
+#[cfg(feature = "std")]
+pub fn sokal_michener(s1: &str, s2: &str) -> f64 {
+    SokalMichener::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Rogers-Tanimoto similarity][1] for two strings.
+///
+/// A wrapper for [`RogersTanimoto`].
+///
+///     use textdistance::str::rogers_tanimoto;
+///     assert_eq!(rogers_tanimoto("abc", "acbd"), 0.6);
+///
+/// [1]: https://doi.org/10.1126/science.132.3434.1115
+#[cfg(feature = "std")]
+pub fn rogers_tanimoto(s1: &str, s2: &str) -> f64 {
+    RogersTanimoto::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [TF-IDF][1] weighted [Cosine similarity][2] for two strings.
+///
+/// A wrapper for [`TfIdf`].
+///
+///     use textdistance::str::tfidf;
+///     assert_eq!(tfidf("abc", "cba"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+/// [2]: https://en.wikipedia.org/wiki/Cosine_similarity
+#[cfg(feature = "std")]
+pub fn tfidf(s1: &str, s2: &str) -> f64 {
+    TfIdf::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Soft TF-IDF similarity][1] for two strings.
+///
+/// A wrapper for [`SoftTfIdf`].
+///
+///     use textdistance::str::soft_tfidf;
+///     assert_eq!(soft_tfidf("abc", "cba"), 1.0);
+///
+/// [1]: https://www.cs.cmu.edu/~wcohen/postscript/ijcai-ws-2003.pdf
+#[cfg(feature = "std")]
+pub fn soft_tfidf(s1: &str, s2: &str) -> f64 {
+    SoftTfIdf::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [Okapi BM25][1] relevance of the second string to the first one.
+///
+/// A wrapper for [`BM25`].
+///
+///     use textdistance::str::bm25;
+///     assert_eq!(bm25("ab", "abab"), 0.625);
+///
+/// [1]: https://en.wikipedia.org/wiki/Okapi_BM25
+#[cfg(feature = "std")]
+pub fn bm25(s1: &str, s2: &str) -> f64 {
+    BM25::default().for_str(s1, s2).nval()
+}
+
 /// Calculate common prefix length for two strings.
 ///
 /// A wrapper for [`Prefix`].
@@ -315,6 +693,48 @@ pub fn entropy_ncd(s1: &str, s2: &str) -> f64 {
     EntropyNCD::default().for_str(s1, s2).nval()
 }
 
+/// Calculate [arithmetic coding]-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`ArithNCD`].
+///
+///     use textdistance::str::arith_ncd;
+///     assert_eq!(arith_ncd("abc", "acbd"), 0.5854815170379906);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [arithmetic coding]: https://en.wikipedia.org/wiki/Arithmetic_coding
+#[cfg(feature = "std")]
+pub fn arith_ncd(s1: &str, s2: &str) -> f64 {
+    ArithNCD::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [BWT]+RLE-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`BWTRLENCD`].
+///
+///     use textdistance::str::bwtrle_ncd;
+///     assert_eq!(bwtrle_ncd("abc", "acbd"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [BWT]: https://en.wikipedia.org/wiki/Burrows%E2%80%93Wheeler_transform
+#[cfg(feature = "std")]
+pub fn bwtrle_ncd(s1: &str, s2: &str) -> f64 {
+    BWTRLENCD::default().for_str(s1, s2).nval()
+}
+
+/// Calculate [LZ77]-based [normalized compression distance][1] for two strings.
+///
+/// A wrapper for [`LZ77NCD`].
+///
+///     use textdistance::str::lz77_ncd;
+///     assert_eq!(lz77_ncd("abc", "acbd"), 1.0);
+///
+/// [1]: https://en.wikipedia.org/wiki/Normalized_compression_distance
+/// [LZ77]: https://en.wikipedia.org/wiki/LZ77_and_LZ78
+#[cfg(feature = "std")]
+pub fn lz77_ncd(s1: &str, s2: &str) -> f64 {
+    LZ77NCD::default().for_str(s1, s2).nval()
+}
+
 /// Calculate [Roberts similarity] for two strings.
 ///
 /// A wrapper for [`Roberts`].
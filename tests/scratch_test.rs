@@ -0,0 +1,106 @@
+//! Check that algorithms don't allocate memory when reusing a scratch.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use textdistance::*;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn run_all(scratch: &mut Scratch, s1: &str, s2: &str) -> f64 {
+    let mut total = 0.;
+    total += Levenshtein::default().for_str_with(scratch, s1, s2).nval();
+    total += LCSSeq::default().for_str_with(scratch, s1, s2).nval();
+    total += LCSStr::default().for_str_with(scratch, s1, s2).nval();
+    total += SmithWaterman::default()
+        .for_str_with(scratch, s1, s2)
+        .nval();
+    total += Jaro::default().for_str_with(scratch, s1, s2).nval();
+    total += DamerauLevenshtein::default()
+        .for_str_with(scratch, s1, s2)
+        .nval();
+    let restricted = DamerauLevenshtein {
+        restricted: true,
+        ..Default::default()
+    };
+    total += restricted.for_str_with(scratch, s1, s2).nval();
+    total
+}
+
+fn run_vecs<E: Eq + core::hash::Hash>(scratch: &mut Scratch, s1: &[E], s2: &[E]) -> f64 {
+    let mut total = 0.;
+    total += Levenshtein::default().for_vec_with(scratch, s1, s2).nval();
+    total += LCSSeq::default().for_vec_with(scratch, s1, s2).nval();
+    total += LCSStr::default().for_vec_with(scratch, s1, s2).nval();
+    total += SmithWaterman::default()
+        .for_vec_with(scratch, s1, s2)
+        .nval();
+    total += Jaro::default().for_vec_with(scratch, s1, s2).nval();
+    for restricted in [false, true] {
+        let a = DamerauLevenshtein {
+            restricted,
+            ..Default::default()
+        };
+        total += a.for_vec_with(scratch, s1, s2).nval();
+    }
+    total
+}
+
+fn run_all_vecs(scratch: &mut Scratch, s1: &str, s2: &str, words1: &[u32], words2: &[u32]) -> f64 {
+    run_vecs(scratch, s1.as_bytes(), s2.as_bytes()) + run_vecs(scratch, words1, words2)
+}
+
+#[test]
+fn test_no_allocations_after_warm_up() {
+    let long1 = "the quick brown fox jumps over the lazy dog ".repeat(3);
+    let long2 = "the lazy dog jumps over the quick brown fox ".repeat(3);
+    let pairs = [
+        ("kitten", "sitting"),
+        ("", "abc"),
+        ("ab", "ba"),
+        (long1.as_str(), long2.as_str()),
+        ("hello", "world"),
+    ];
+    // the same texts as sequences of word lengths
+    let words: Vec<(Vec<u32>, Vec<u32>)> = pairs
+        .iter()
+        .map(|(s1, s2)| {
+            let lens = |s: &str| s.split(' ').map(|w| w.len() as u32).collect();
+            (lens(s1), lens(s2))
+        })
+        .collect();
+    let mut scratch = Scratch::default();
+    for ((s1, s2), (w1, w2)) in pairs.iter().zip(&words) {
+        run_all(&mut scratch, s1, s2);
+        run_all_vecs(&mut scratch, s1, s2, w1, w2);
+    }
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let mut total = 0.;
+    for ((s1, s2), (w1, w2)) in pairs.iter().zip(&words) {
+        total += run_all(&mut scratch, s1, s2);
+        total += run_all_vecs(&mut scratch, s1, s2, w1, w2);
+    }
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+    assert!(total > 0.);
+    assert_eq!(after, before);
+}